@@ -0,0 +1,227 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::float_traits::{Abs, FloatConst, FromF64, Sqrt, Trig};
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: FloatConst> Complex<T> {
+    pub fn from_real(re: T) -> Self {
+        Self { re, im: T::ZERO }
+    }
+}
+
+/// The real constants, `EPSILON` is the real machine epsilon
+impl<T: FloatConst> FloatConst for Complex<T> {
+    const EPSILON: Self = Self {
+        re: T::EPSILON,
+        im: T::ZERO,
+    };
+    const ZERO: Self = Self {
+        re: T::ZERO,
+        im: T::ZERO,
+    };
+    const ONE: Self = Self {
+        re: T::ONE,
+        im: T::ZERO,
+    };
+}
+
+impl<T: FromF64 + FloatConst> FromF64 for Complex<T> {
+    fn from_f64(x: f64) -> Self {
+        Self::from_real(T::from_f64(x))
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Copy,
+{
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn scale(self, k: T) -> Self {
+        Self::new(self.re * k, self.im * k)
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Div<Output = T> + Abs + Sqrt + Copy,
+{
+    /// Modulus `|z|`, scaled to avoid overflow of the squares
+    pub fn norm(self) -> T {
+        let (a, b) = (self.re.abs(), self.im.abs());
+        let (big, small) = if a >= b { (a, b) } else { (b, a) };
+        if big == T::ZERO {
+            return T::ZERO;
+        }
+        let r = small / big;
+        big * (T::ONE + r * r).sqrt()
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Mul<Output = T> + Trig + Copy,
+{
+    /// `r * e^(i * theta)`
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + Abs
+        + Sqrt
+        + Copy,
+{
+    /// Principal square root, with non-negative real part
+    pub fn sqrt(self) -> Self {
+        let two = T::ONE + T::ONE;
+        let r = self.norm();
+        if r == T::ZERO {
+            return self;
+        }
+        let re = ((r + self.re.abs()) / two).sqrt();
+        if self.re >= T::ZERO {
+            Self::new(re, self.im / (two * re))
+        } else {
+            let im = if self.im >= T::ZERO { re } else { -re };
+            Self::new(self.im.abs() / (two * re), im)
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Abs + Copy,
+{
+    type Output = Self;
+
+    /// Smith's algorithm, avoids overflow in `|rhs|^2`
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + r * rhs.im;
+            Self::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.im + r * rhs.re;
+            Self::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T> Display for Complex<T>
+where
+    T: Display + PartialOrd + Abs + FloatConst + Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im < T::ZERO {
+            write!(f, "{} - {}i", self.re, self.im.abs())
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -4.0);
+        assert!(a + b == Complex::new(4.0, -2.0));
+        assert!(a - b == Complex::new(-2.0, 6.0));
+        assert!(a * b == Complex::new(11.0, 2.0));
+        assert!((a * b) / b == a);
+        assert!(b.norm() == 5.0);
+        assert!(-a == Complex::new(-1.0, -2.0));
+        assert!(a.conj() == Complex::new(1.0, -2.0));
+        println!("a = {a}, b = {b}");
+    }
+
+    #[test]
+    fn test_sqrt() {
+        for z in [
+            Complex::new(3.0, 4.0),
+            Complex::new(-3.0, 4.0),
+            Complex::new(-3.0, -4.0),
+            Complex::new(-4.0, 0.0),
+            Complex::new(0.0, 2.0),
+        ] {
+            let s = z.sqrt();
+            assert!(s.re >= 0.0);
+            assert!(
+                (s * s - z).norm().abs_diff_eq(&0.0, 1e-14),
+                "sqrt({z}) = {s}"
+            );
+        }
+    }
+}
@@ -1,2 +1,4 @@
 /// Types implement this trait is considered as a continuous function
 pub trait ContinuousFn {}
+
+impl<F: ContinuousFn + ?Sized> ContinuousFn for &F {}
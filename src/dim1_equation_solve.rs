@@ -1,3 +1,11 @@
+pub mod all_roots;
 pub mod bisection;
+pub mod bracket;
 pub mod brent;
 pub mod find_root;
+pub mod fixed_point;
+pub mod inverse_interpolation;
+pub mod muller;
+pub mod newton;
+pub mod safeguarded;
+pub mod secant;
@@ -0,0 +1,358 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Range, Sub},
+};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::Dim1Fn,
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64, MaxMin, Sqrt},
+};
+
+use super::{
+    brent::brent_solve,
+    find_root::{FindRootError, FindRootProblem, IterStopCondition, SolveResult, StopReason},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct AllRootsOptions<T> {
+    initial_intervals: usize,
+    max_refine_depth: usize,
+    tangent_tolorency: T,
+    dedup_tolorency: T,
+}
+
+impl<T: FloatConst + PartialOrd + Sqrt + Copy> AllRootsOptions<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of equal sub-intervals of the first sampling pass
+    pub fn with_initial_intervals(mut self, intervals: usize) -> Self {
+        assert!(intervals > 0);
+        self.initial_intervals = intervals;
+        self
+    }
+
+    /// Maximum number of halvings spent around a local minimum of `|f|`
+    pub fn with_max_refine_depth(mut self, depth: usize) -> Self {
+        self.max_refine_depth = depth;
+        self
+    }
+
+    /// A local minimum of `|f|` without sign change is accepted as a (tangent) root
+    /// if `|f|` falls below this tolorency
+    pub fn with_tangent_tolorency(mut self, tol: T) -> Self {
+        assert!(tol >= T::ZERO);
+        self.tangent_tolorency = tol;
+        self
+    }
+
+    /// Roots closer than `dedup_tolorency * max(|x|, 1)` are reported once
+    pub fn with_dedup_tolorency(mut self, tol: T) -> Self {
+        assert!(tol >= T::ZERO);
+        self.dedup_tolorency = tol;
+        self
+    }
+
+    pub fn initial_intervals(&self) -> usize {
+        self.initial_intervals
+    }
+
+    pub fn max_refine_depth(&self) -> usize {
+        self.max_refine_depth
+    }
+
+    pub fn tangent_tolorency(&self) -> T {
+        self.tangent_tolorency
+    }
+
+    pub fn dedup_tolorency(&self) -> T {
+        self.dedup_tolorency
+    }
+}
+
+impl<T: FloatConst + Sqrt> Default for AllRootsOptions<T> {
+    fn default() -> Self {
+        Self {
+            initial_intervals: 64,
+            max_refine_depth: 64,
+            tangent_tolorency: T::EPSILON,
+            dedup_tolorency: T::EPSILON.sqrt(),
+        }
+    }
+}
+
+/// Finds all roots of `func` in `range`, sorted by x.
+///
+/// `range` is sampled on a uniform grid, every cell with a sign change is solved with
+/// [`brent_solve`]. Around each local minimum of `|f|` on the grid, the cell is halved
+/// repeatedly to reveal hidden sign changes of close root pairs, or tangent roots where
+/// `|f|` reaches the tangent tolorency of `options`.
+pub fn all_roots_in<T, F>(
+    func: &F,
+    range: Range<T>,
+    stop_cond: &IterStopCondition<T>,
+    options: &AllRootsOptions<T>,
+) -> Result<Vec<SolveResult<T>>, FindRootError>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite
+        + Debug,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    stop_cond.validate()?;
+    let (a, b) = (range.start, range.end);
+    if a.is_nan() || b.is_nan() {
+        return Err(FindRootError::NanAtEndpoint);
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return Err(FindRootError::NonFiniteEndpoint);
+    }
+    if a > b {
+        return Err(FindRootError::ReversedRange);
+    }
+
+    let n = options.initial_intervals;
+    let xs: Vec<T> = (0..=n)
+        .map(|i| {
+            if i == n {
+                b
+            } else {
+                a + (b - a) * T::from_f64(i as f64) / T::from_f64(n as f64)
+            }
+        })
+        .collect();
+    let ys: Vec<T> = xs.iter().map(|x| func.eval(*x)).collect();
+
+    let mut roots = Vec::default();
+
+    for i in 0..=n {
+        if ys[i] == T::ZERO {
+            roots.push(SolveResult {
+                root: xs[i],
+                iter_count: 0,
+                stop_reason: StopReason::TolorencyY,
+            });
+        }
+    }
+
+    for i in 0..n {
+        if ys[i] * ys[i + 1] < T::ZERO {
+            roots.push(solve_bracket(func, xs[i]..xs[i + 1], stop_cond));
+        }
+    }
+
+    for i in 1..n {
+        let (y_l, y_m, y_r) = (ys[i - 1], ys[i], ys[i + 1]);
+        if y_l * y_m > T::ZERO
+            && y_m * y_r > T::ZERO
+            && y_m.abs() <= y_l.abs()
+            && y_m.abs() <= y_r.abs()
+        {
+            refine_minimum(
+                func,
+                [(xs[i - 1], y_l), (xs[i], y_m), (xs[i + 1], y_r)],
+                stop_cond,
+                options,
+                &mut roots,
+            );
+        }
+    }
+
+    // a NaN of `func` inside a cell can turn into a NaN root
+    roots.retain(|r| r.root.is_finite());
+    roots.sort_by(|r0, r1| r0.root.partial_cmp(&r1.root).unwrap());
+    roots.dedup_by(|r1, r0| {
+        (r1.root - r0.root).abs() <= options.dedup_tolorency * r0.root.abs().max(T::ONE)
+    });
+
+    Ok(roots)
+}
+
+fn solve_bracket<T, F>(
+    func: &F,
+    range: Range<T>,
+    stop_cond: &IterStopCondition<T>,
+) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Debug,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    brent_solve(&FindRootProblem::new_unchecked(func, range), stop_cond)
+}
+
+/// Halves the cell around `center`, the point of smallest `|f|` among the three, until a
+/// sign change shows up or the cell collapses
+fn refine_minimum<T, F>(
+    func: &F,
+    [mut left, mut center, mut right]: [(T, T); 3],
+    stop_cond: &IterStopCondition<T>,
+    options: &AllRootsOptions<T>,
+    roots: &mut Vec<SolveResult<T>>,
+) where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Debug,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    let mut depth = 0;
+    while depth < options.max_refine_depth {
+        if right.0 - left.0 <= stop_cond.x_tolorency() * fl!(2.0) * center.0.abs().max(T::ONE) {
+            break;
+        }
+
+        let l = (left.0 + center.0) / fl!(2.0);
+        let l = (l, func.eval(l));
+        let r = (center.0 + right.0) / fl!(2.0);
+        let r = (r, func.eval(r));
+
+        let points = [left, l, center, r, right];
+        if points.iter().any(|p| p.1 * center.1 <= T::ZERO) {
+            for w in points.windows(2) {
+                if w[0].1 == T::ZERO {
+                    roots.push(SolveResult {
+                        root: w[0].0,
+                        iter_count: depth,
+                        stop_reason: StopReason::TolorencyY,
+                    });
+                } else if w[0].1 * w[1].1 < T::ZERO {
+                    roots.push(solve_bracket(func, w[0].0..w[1].0, stop_cond));
+                }
+            }
+            return;
+        }
+
+        if l.1.abs() < center.1.abs() {
+            (left, center, right) = (left, l, center);
+        } else if r.1.abs() < center.1.abs() {
+            (left, center, right) = (center, r, right);
+        } else {
+            (left, right) = (l, r);
+        }
+        depth += 1;
+    }
+
+    if center.1.abs() <= options.tangent_tolorency {
+        roots.push(SolveResult {
+            root: center.0,
+            iter_count: depth,
+            stop_reason: StopReason::TolorencyY,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::dim1_func::polynomial::Polynomial;
+
+    struct Sin3x;
+
+    impl Dim1Fn<f64> for Sin3x {
+        fn eval(&self, x: f64) -> f64 {
+            (3.0 * x).sin()
+        }
+    }
+
+    impl ContinuousFn for Sin3x {}
+
+    #[test]
+    fn test_non_finite() {
+        let stop_cond = IterStopCondition::new();
+        let options = AllRootsOptions::new();
+        assert!(
+            all_roots_in(&Sin3x, f64::NEG_INFINITY..3.0, &stop_cond, &options).unwrap_err()
+                == FindRootError::NonFiniteEndpoint
+        );
+        assert!(
+            all_roots_in(&Sin3x, -3.0..f64::INFINITY, &stop_cond, &options).unwrap_err()
+                == FindRootError::NonFiniteEndpoint
+        );
+    }
+
+    #[test]
+    fn test_sign_change_roots() {
+        let roots = all_roots_in(
+            &Sin3x,
+            -3.0..3.0,
+            &IterStopCondition::new(),
+            &AllRootsOptions::new().with_initial_intervals(7),
+        )
+        .unwrap();
+        assert!(roots.len() == 5);
+        for (k, root) in (-2..=2).zip(roots.iter()) {
+            let expected = k as f64 * std::f64::consts::PI / 3.0;
+            assert!(
+                root.root().abs_diff_eq(&expected, 1e-12),
+                "root = {}",
+                root.root()
+            );
+        }
+    }
+
+    #[test]
+    fn test_tangent_and_close_roots() {
+        // double root at 1, close pair at 2.5 and 2.501 inside one grid cell
+        let f = Polynomial::new(1.0).with_base_points(&[-2.0, 1.0, 1.0, 2.5, 2.501]);
+        let roots = all_roots_in(
+            &f,
+            -3.0..3.3,
+            &IterStopCondition::new(),
+            &AllRootsOptions::new().with_initial_intervals(10),
+        )
+        .unwrap();
+        for root in roots.iter() {
+            println!("root = {}, {:?}", root.root(), root.stop_reason());
+        }
+        assert!(roots.len() == 4);
+        assert!(roots[0].root().abs_diff_eq(&-2.0, 1e-12));
+        assert!(roots[1].root().abs_diff_eq(&1.0, 1e-6));
+        assert!(roots[2].root().abs_diff_eq(&2.5, 1e-12));
+        assert!(roots[3].root().abs_diff_eq(&2.501, 1e-12));
+    }
+
+    #[test]
+    fn test_root_on_grid_node() {
+        let f = Polynomial::new(1.0).with_base_points(&[0.0, 0.5]);
+        let roots = all_roots_in(
+            &f,
+            -1.0..1.0,
+            &IterStopCondition::new(),
+            &AllRootsOptions::new().with_initial_intervals(4),
+        )
+        .unwrap();
+        assert!(roots.len() == 2);
+        assert!(roots[0].root() == 0.0 && roots[1].root() == 0.5);
+    }
+}
@@ -3,10 +3,12 @@ use std::ops::{Add, Div, Mul, Sub};
 use crate::{
     continuous_func::ContinuousFn,
     dim1_func::Dim1Fn,
-    float_traits::{fl, Abs, FloatConst, FromF64, MaxMin},
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64, MaxMin},
 };
 
-use super::find_root::{FindRootProblem, IterStopCondition, SolveResult, StopReason};
+use super::find_root::{
+    FindRootError, FindRootProblem, IterStopCondition, SolveResult, StopReason,
+};
 
 #[allow(clippy::collapsible_else_if)]
 pub fn bisection_solve<T, F>(
@@ -90,6 +92,32 @@ where
     }
 }
 
+/// Same as [`bisection_solve`], but returns an error for an invalid `problem` or `stop_cond`
+/// instead of assuming them valid
+pub fn try_bisection_solve<T, F>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+) -> Result<SolveResult<T>, FindRootError>
+where
+    T: Mul<Output = T>
+        + PartialOrd
+        + MaxMin
+        + Copy
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + FloatConst
+        + Add<Output = T>
+        + Abs
+        + FromF64
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    problem.validate()?;
+    stop_cond.validate()?;
+    Ok(bisection_solve(problem, stop_cond))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +174,45 @@ mod tests {
         assert!(result.stop_reason() == StopReason::TolorencyX);
         assert!(result.root().relative_eq(&10000.0, 1e-16, 1e-13));
     }
+
+    #[test]
+    fn test_try_solve() {
+        let f = Polynomial::new(1.0).with_coefficients(&[1.0]);
+        let stop_cond = IterStopCondition::new().with_x_tolorency(1e-6);
+
+        let problem = FindRootProblem::new_unchecked(f.clone(), -2.0..3.0);
+        let result = try_bisection_solve(&problem, &stop_cond).unwrap();
+        assert!(result.root().abs_diff_eq(&-1.0, 1e-6));
+
+        let problem = FindRootProblem::new_unchecked(f.clone(), 0.0..3.0);
+        assert!(
+            try_bisection_solve(&problem, &stop_cond).unwrap_err() == FindRootError::NoSignChange
+        );
+
+        // the midpoint of an infinite range is not finite, bisection would never stop
+        for range in [f64::NEG_INFINITY..1.0, f64::NEG_INFINITY..f64::INFINITY] {
+            let problem = FindRootProblem::new_unchecked(f.clone(), range);
+            assert!(
+                try_bisection_solve(&problem, &stop_cond).unwrap_err()
+                    == FindRootError::NonFiniteEndpoint
+            );
+        }
+
+        let problem = FindRootProblem::new_unchecked(f, -2.0..3.0);
+        let stop_cond = IterStopCondition::new().with_y_tolorency(f64::INFINITY);
+        assert!(
+            try_bisection_solve(&problem, &stop_cond).unwrap_err()
+                == FindRootError::NonFiniteTolerance
+        );
+
+        // user supplied tolorencies go through the fallible setters, nothing panics
+        let solve = |x_tol: f64| {
+            IterStopCondition::new()
+                .try_with_x_tolorency(x_tol)
+                .and_then(|stop_cond| try_bisection_solve(&problem, &stop_cond))
+        };
+        assert!(solve(f64::NAN).unwrap_err() == FindRootError::NonFiniteTolerance);
+        assert!(solve(-1e-6).unwrap_err() == FindRootError::NegativeTolerance);
+        assert!(solve(1e-6).unwrap().root().abs_diff_eq(&-1.0, 1e-6));
+    }
 }
@@ -0,0 +1,247 @@
+use std::ops::{Add, Div, Mul, Range, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::Dim1Fn,
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64},
+};
+
+use super::find_root::{FindRootError, FindRootProblem};
+
+/// Expands `initial` geometrically, moving the end point with the smaller `|f|` outward
+/// by 1.6 times the current width, until `f` changes sign. At most `eval_budget`
+/// evaluations of `func` are spent before [`FindRootError::EvalBudgetExhausted`] is returned.
+///
+/// An expanded end point where `f` is exactly zero does not stop the search, as a decaying
+/// `f` underflows to zero long before it reaches a root. An end point that overflows gives
+/// [`FindRootError::NonFiniteEndpoint`], and the bracket is checked with
+/// [`FindRootProblem::validate`] before it is returned.
+pub fn expand_bracket<T, F>(
+    func: F,
+    initial: Range<T>,
+    eval_budget: usize,
+) -> Result<FindRootProblem<T, F>, FindRootError>
+where
+    T: PartialOrd
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + FloatConst
+        + FromF64
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    let (mut a, mut b) = (initial.start, initial.end);
+    if a.is_nan() || b.is_nan() {
+        return Err(FindRootError::NanAtEndpoint);
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return Err(FindRootError::NonFiniteEndpoint);
+    }
+    if a >= b {
+        return Err(FindRootError::ReversedRange);
+    }
+    if eval_budget < 2 {
+        return Err(FindRootError::EvalBudgetExhausted);
+    }
+
+    let eval = |x: T| {
+        let y = func.eval(x);
+        if y.is_nan() {
+            Err(FindRootError::NanAtEndpoint)
+        } else {
+            Ok(y)
+        }
+    };
+
+    let (mut f_a, mut f_b) = (eval(a)?, eval(b)?);
+    let mut eval_count = 2;
+
+    if f_a * f_b <= T::ZERO {
+        return FindRootProblem::try_new(func, a..b);
+    }
+    while f_a * f_b >= T::ZERO {
+        if eval_count >= eval_budget {
+            return Err(FindRootError::EvalBudgetExhausted);
+        }
+
+        if f_a.abs() < f_b.abs() {
+            a = a + (a - b) * fl!(1.6);
+            f_a = eval(a)?;
+        } else {
+            b = b + (b - a) * fl!(1.6);
+            f_b = eval(b)?;
+        }
+        if !a.is_finite() || !b.is_finite() {
+            return Err(FindRootError::NonFiniteEndpoint);
+        }
+        eval_count += 1;
+    }
+
+    FindRootProblem::try_new(func, a..b)
+}
+
+/// Expands `x0 - step..x0 + step` with [`expand_bracket`]
+pub fn bracket_from_point<T, F>(
+    func: F,
+    x0: T,
+    step: T,
+    eval_budget: usize,
+) -> Result<FindRootProblem<T, F>, FindRootError>
+where
+    T: PartialOrd
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + FloatConst
+        + FromF64
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    expand_bracket(func, (x0 - step)..(x0 + step), eval_budget)
+}
+
+/// Splits `range` into `intervals` equal sub-intervals and returns the leftmost one over
+/// which `f` changes sign, spending `intervals + 1` evaluations at most
+pub fn scan_bracket<T, F>(
+    func: F,
+    range: Range<T>,
+    intervals: usize,
+) -> Result<FindRootProblem<T, F>, FindRootError>
+where
+    T: PartialOrd
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    let (a, b) = (range.start, range.end);
+    if a.is_nan() || b.is_nan() {
+        return Err(FindRootError::NanAtEndpoint);
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return Err(FindRootError::NonFiniteEndpoint);
+    }
+    if a >= b {
+        return Err(FindRootError::ReversedRange);
+    }
+
+    let n = intervals.max(1);
+    let width = b - a;
+    let node = |i: usize| {
+        if i == n {
+            b
+        } else {
+            a + width * T::from_f64(i as f64) / T::from_f64(n as f64)
+        }
+    };
+
+    let mut left = a;
+    let mut f_left = func.eval(left);
+    for i in 1..=n {
+        let right = node(i);
+        let f_right = func.eval(right);
+        if f_left * f_right <= T::ZERO {
+            return Ok(FindRootProblem::new_unchecked(func, left..right));
+        }
+        left = right;
+        f_left = f_right;
+    }
+
+    Err(FindRootError::NoSignChange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::{brent::brent_solve, find_root::IterStopCondition},
+        dim1_func::polynomial::Polynomial,
+    };
+
+    /// `1 / (1 + x^2)`, positive everywhere
+    #[derive(Debug)]
+    struct Lorentzian;
+
+    impl Dim1Fn<f64> for Lorentzian {
+        fn eval(&self, x: f64) -> f64 {
+            1.0 / (1.0 + x * x)
+        }
+    }
+
+    impl ContinuousFn for Lorentzian {}
+
+    #[test]
+    fn test_expand_bracket() {
+        let f = Polynomial::new(1.0).with_coefficients(&[-100.0]);
+        let problem = expand_bracket(f, 0.0..1.0, 50).unwrap();
+        assert!(problem.is_valid());
+        let range = problem.search_range();
+        println!("bracket = {range:?}");
+        assert!(range.start <= 100.0 && range.end >= 100.0);
+
+        let result = brent_solve(&problem, &IterStopCondition::new());
+        assert!(result.root().abs_diff_eq(&100.0, 1e-12));
+    }
+
+    #[test]
+    fn test_bracket_from_point() {
+        let f = Polynomial::new(1.0).with_base_points(&[-7.0, 3.0]);
+        let problem = bracket_from_point(f, 1.0, 0.1, 50).unwrap();
+        assert!(problem.is_valid());
+    }
+
+    #[test]
+    fn test_eval_budget_exhausted() {
+        let f = Polynomial::new(1.0).with_coefficients(&[1.0, 0.0]);
+        assert!(
+            expand_bracket(f.clone(), -1.0..1.0, 20).unwrap_err()
+                == FindRootError::EvalBudgetExhausted
+        );
+        assert!(expand_bracket(f, 1.0..-1.0, 20).unwrap_err() == FindRootError::ReversedRange);
+    }
+
+    #[test]
+    fn test_expand_bracket_non_finite() {
+        let f = Polynomial::new(1.0).with_coefficients(&[-100.0]);
+        assert!(
+            expand_bracket(f, 0.0..f64::INFINITY, 50).unwrap_err()
+                == FindRootError::NonFiniteEndpoint
+        );
+
+        // the function underflows to zero without a sign change, the end point overflows
+        assert!(
+            expand_bracket(Lorentzian, 0.0..1.0, 5000).unwrap_err()
+                == FindRootError::NonFiniteEndpoint
+        );
+    }
+
+    #[test]
+    fn test_scan_bracket() {
+        let f = Polynomial::new(1.0).with_base_points(&[0.35, 0.4, 0.9]);
+        let problem = scan_bracket(f.clone(), 0.0..1.0, 10).unwrap();
+        assert!(problem.is_valid());
+        let range = problem.search_range();
+        assert!(range.start.abs_diff_eq(&0.3, 1e-12) && range.end.abs_diff_eq(&0.4, 1e-12));
+
+        // the two close roots cancel out on the coarse grid
+        let f = Polynomial::new(1.0).with_base_points(&[0.32, 0.38]);
+        assert!(scan_bracket(f.clone(), 0.0..1.0, 10).unwrap_err() == FindRootError::NoSignChange);
+        assert!(
+            scan_bracket(f, f64::NEG_INFINITY..1.0, 10).unwrap_err()
+                == FindRootError::NonFiniteEndpoint
+        );
+    }
+}
@@ -6,10 +6,12 @@ use std::{
 use crate::{
     continuous_func::ContinuousFn,
     dim1_func::Dim1Fn,
-    float_traits::{fl, Abs, FloatConst, FromF64, MaxMin},
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64, MaxMin},
 };
 
-use super::find_root::{FindRootProblem, IterStopCondition, SolveResult, StopReason};
+use super::find_root::{
+    FindRootError, FindRootProblem, IterStopCondition, SolveResult, StopReason,
+};
 
 pub fn brent_solve<T, F>(
     problem: &FindRootProblem<T, F>,
@@ -144,6 +146,33 @@ where
     }
 }
 
+/// Same as [`brent_solve`], but returns an error for an invalid `problem` or `stop_cond`
+/// instead of assuming them valid
+pub fn try_brent_solve<T, F>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+) -> Result<SolveResult<T>, FindRootError>
+where
+    T: Mul<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + FloatConst
+        + Add<Output = T>
+        + Abs
+        + Debug
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    problem.validate()?;
+    stop_cond.validate()?;
+    Ok(brent_solve(problem, stop_cond))
+}
+
 #[derive(Clone, Copy, Debug)]
 struct Point<T> {
     x: T,
@@ -285,7 +314,7 @@ where
 
 #[allow(non_snake_case)]
 #[inline]
-fn inverse_quad_interpolation<T>((a, b, c): (T, T, T), (A, B, C): (T, T, T)) -> T
+pub(super) fn inverse_quad_interpolation<T>((a, b, c): (T, T, T), (A, B, C): (T, T, T)) -> T
 where
     T: Sub<Output = T>
         + Add<Output = T>
@@ -349,4 +378,20 @@ mod tests {
         println!("root = {}", result.root());
         println!("f(root) = {}", problem.func_eval(result.root()));
     }
+
+    #[test]
+    fn test_try_solve() {
+        let f = Polynomial::default().with_coefficients(&[2.0, -3.0]);
+        let stop_cond = IterStopCondition::new();
+
+        let problem = FindRootProblem::new_unchecked(f.clone(), 1.5..300.0);
+        let result = try_brent_solve(&problem, &stop_cond).unwrap();
+        assert!(result.root().abs_diff_eq(&2.0, 1e-12));
+
+        let problem = FindRootProblem::new_unchecked(f.clone(), 300.0..1.5);
+        assert!(try_brent_solve(&problem, &stop_cond).unwrap_err() == FindRootError::ReversedRange);
+
+        let problem = FindRootProblem::new_unchecked(f, f64::NAN..300.0);
+        assert!(try_brent_solve(&problem, &stop_cond).unwrap_err() == FindRootError::NanAtEndpoint);
+    }
 }
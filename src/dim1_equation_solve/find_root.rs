@@ -1,6 +1,57 @@
-use std::ops::{Mul, Range};
+use std::{
+    fmt::Display,
+    ops::{Mul, Range},
+};
 
-use crate::{continuous_func::ContinuousFn, dim1_func::Dim1Fn, float_traits::FloatConst};
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::Dim1Fn,
+    float_traits::{Finite, FloatConst},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FindRootError {
+    /// `f(start)` and `f(end)` have the same sign
+    NoSignChange,
+    /// An end point of the search range, or the function value there, is NaN
+    NanAtEndpoint,
+    /// An end point of the search range is infinite
+    NonFiniteEndpoint,
+    /// `start > end`, or an empty range where a non-empty one is required
+    ReversedRange,
+    /// A tolerance of the stop condition is infinite or NaN
+    NonFiniteTolerance,
+    /// A tolerance of the stop condition is negative
+    NegativeTolerance,
+    /// No sign change was found within the allowed number of function evaluations
+    EvalBudgetExhausted,
+}
+
+impl Display for FindRootError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindRootError::NoSignChange => {
+                write!(f, "function has no sign change over the search range")
+            }
+            FindRootError::NanAtEndpoint => write!(f, "NaN at an end point of the search range"),
+            FindRootError::NonFiniteEndpoint => {
+                write!(f, "end point of the search range is infinite")
+            }
+            FindRootError::ReversedRange => write!(f, "search range start is after its end"),
+            FindRootError::NonFiniteTolerance => {
+                write!(f, "stop condition tolerance is not finite")
+            }
+            FindRootError::NegativeTolerance => {
+                write!(f, "stop condition tolerance is negative")
+            }
+            FindRootError::EvalBudgetExhausted => {
+                write!(f, "no sign change found within the evaluation budget")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FindRootError {}
 
 #[derive(Clone, Debug)]
 pub struct FindRootProblem<T, F> {
@@ -31,6 +82,47 @@ where
         p
     }
 
+    /// Checks the problem has a finite, ordered range over which the function changes sign
+    pub fn validate(&self) -> Result<(), FindRootError>
+    where
+        T: Finite,
+    {
+        let (a, b) = (self.search_range.start, self.search_range.end);
+        if a.is_nan() || b.is_nan() {
+            return Err(FindRootError::NanAtEndpoint);
+        }
+        if !a.is_finite() || !b.is_finite() {
+            return Err(FindRootError::NonFiniteEndpoint);
+        }
+        if a > b {
+            return Err(FindRootError::ReversedRange);
+        }
+
+        let f_a = self.func.eval(a);
+        let f_b = self.func.eval(b);
+        if f_a.is_nan() || f_b.is_nan() {
+            return Err(FindRootError::NanAtEndpoint);
+        }
+        if f_a * f_b > T::ZERO {
+            return Err(FindRootError::NoSignChange);
+        }
+
+        Ok(())
+    }
+
+    pub fn try_new(func: F, search_range: Range<T>) -> Result<Self, FindRootError>
+    where
+        T: Finite,
+    {
+        let p = Self::new_unchecked(func, search_range);
+        p.validate()?;
+        Ok(p)
+    }
+
+    pub fn func(&self) -> &F {
+        &self.func
+    }
+
     pub fn search_range(&self) -> Range<T> {
         self.search_range.clone()
     }
@@ -40,6 +132,11 @@ where
     }
 }
 
+/// Iteration limit of the solvers that do not terminate on their own, such as Newton's
+/// method, when the stop condition sets none. A cycle or a slow drift then stops with
+/// [`StopReason::IterCountLimit`] instead of hanging.
+pub const DEFAULT_OPEN_ITER_COUNT_LIMIT: usize = 10_000;
+
 #[derive(Clone, Copy, Debug)]
 pub struct IterStopCondition<T> {
     x_tolorency: T,
@@ -64,6 +161,26 @@ impl<T: FloatConst + PartialOrd + FloatConst + Copy> IterStopCondition<T> {
         self
     }
 
+    /// Same as [`IterStopCondition::with_x_tolorency`], returning an error instead of
+    /// panicking on a negative, infinite or NaN tolorency
+    pub fn try_with_x_tolorency(self, x_tol: T) -> Result<Self, FindRootError>
+    where
+        T: Finite,
+    {
+        check_tolorency(x_tol)?;
+        Ok(self.with_x_tolorency(x_tol))
+    }
+
+    /// Same as [`IterStopCondition::with_y_tolorency`], returning an error instead of
+    /// panicking on a negative, infinite or NaN tolorency
+    pub fn try_with_y_tolorency(self, y_tol: T) -> Result<Self, FindRootError>
+    where
+        T: Finite,
+    {
+        check_tolorency(y_tol)?;
+        Ok(self.with_y_tolorency(y_tol))
+    }
+
     pub fn with_iter_count_limit(mut self, limit: usize) -> Self {
         self.iter_count_limit = Some(limit);
         self
@@ -80,6 +197,30 @@ impl<T: FloatConst + PartialOrd + FloatConst + Copy> IterStopCondition<T> {
     pub fn iter_count_limit(&self) -> Option<usize> {
         self.iter_count_limit
     }
+
+    /// The iteration limit, or [`DEFAULT_OPEN_ITER_COUNT_LIMIT`] if none is set
+    pub fn open_iter_count_limit(&self) -> usize {
+        self.iter_count_limit
+            .unwrap_or(DEFAULT_OPEN_ITER_COUNT_LIMIT)
+    }
+
+    pub fn validate(&self) -> Result<(), FindRootError>
+    where
+        T: Finite,
+    {
+        check_tolorency(self.x_tolorency)?;
+        check_tolorency(self.y_tolorency)
+    }
+}
+
+fn check_tolorency<T: FloatConst + PartialOrd + Finite>(tol: T) -> Result<(), FindRootError> {
+    if !tol.is_finite() {
+        Err(FindRootError::NonFiniteTolerance)
+    } else if tol < T::ZERO {
+        Err(FindRootError::NegativeTolerance)
+    } else {
+        Ok(())
+    }
 }
 
 impl<T: FloatConst> Default for IterStopCondition<T> {
@@ -97,6 +238,10 @@ pub enum StopReason {
     TolorencyX,
     TolorencyY,
     IterCountLimit,
+    /// The derivative vanished, so no further Newton-like step can be taken
+    ZeroDerivative,
+    /// The iteration produced a non-finite value
+    Diverging,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -143,4 +288,38 @@ mod tests {
 
         assert!(problem.func_eval(2.0) == 3.0);
     }
+
+    #[test]
+    fn test_try_new() {
+        let f = Polynomial::new(1.0).with_coefficients(&[1.0]);
+        assert!(FindRootProblem::try_new(f.clone(), -1.0..3.0).is_ok());
+        assert!(
+            FindRootProblem::try_new(f.clone(), 1.0..2.0).unwrap_err()
+                == FindRootError::NoSignChange
+        );
+        assert!(
+            FindRootProblem::try_new(f.clone(), 3.0..-1.0).unwrap_err()
+                == FindRootError::ReversedRange
+        );
+        assert!(
+            FindRootProblem::try_new(f.clone(), f64::NAN..3.0).unwrap_err()
+                == FindRootError::NanAtEndpoint
+        );
+        assert!(
+            FindRootProblem::try_new(Polynomial::new(f64::NAN), -1.0..3.0).unwrap_err()
+                == FindRootError::NanAtEndpoint
+        );
+    }
+
+    #[test]
+    fn test_validate_stop_condition() {
+        assert!(IterStopCondition::<f64>::new().validate().is_ok());
+        assert!(
+            IterStopCondition::new()
+                .with_x_tolorency(f64::INFINITY)
+                .validate()
+                .unwrap_err()
+                == FindRootError::NonFiniteTolerance
+        );
+    }
 }
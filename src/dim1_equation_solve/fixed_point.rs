@@ -0,0 +1,335 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    dim1_func::Dim1Fn,
+    float_traits::{Abs, Finite, FloatConst, MaxMin},
+};
+
+use super::find_root::{IterStopCondition, SolveResult, StopReason};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FixedPointAcceleration {
+    /// Plain iteration `x_{k+1} = g(x_k)`
+    None,
+    /// Aitken's Δ² extrapolation applied after every two evaluations of `g`
+    Steffensen,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FixedPointSolveResult<T> {
+    solve_result: SolveResult<T>,
+    contraction_rate: Option<T>,
+}
+
+impl<T> FixedPointSolveResult<T>
+where
+    T: Clone,
+{
+    pub fn solve_result(&self) -> SolveResult<T> {
+        self.solve_result.clone()
+    }
+
+    pub fn root(&self) -> T {
+        self.solve_result.root()
+    }
+
+    pub fn iter_count(&self) -> usize {
+        self.solve_result.iter_count()
+    }
+
+    pub fn stop_reason(&self) -> StopReason {
+        self.solve_result.stop_reason()
+    }
+
+    /// Last estimate of `|g'(x)|` near the fixed point, `|x_{k+1} - x_k| / |x_k - x_{k-1}|`
+    pub fn contraction_rate(&self) -> Option<T> {
+        self.contraction_rate.clone()
+    }
+}
+
+/// Solves `x = g(x)` by fixed point iteration starting from `initial`.
+///
+/// `y_tolorency` of `stop_cond` is compared with the residual `|g(x) - x|`.
+/// Without acceleration the iteration stops with [`StopReason::Diverging`] as soon as
+/// the estimated contraction rate reaches 1. Steffensen acceleration converges even
+/// for non contractive `g`, so its rate estimate is only reported.
+///
+/// Without an iteration limit in `stop_cond`, stops after
+/// [`DEFAULT_OPEN_ITER_COUNT_LIMIT`](super::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT)
+/// iterations.
+pub fn fixed_point_solve<T, F>(
+    g: &F,
+    initial: T,
+    stop_cond: &IterStopCondition<T>,
+    acceleration: FixedPointAcceleration,
+) -> FixedPointSolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: Dim1Fn<T>,
+{
+    match acceleration {
+        FixedPointAcceleration::None => plain_iteration(g, initial, stop_cond),
+        FixedPointAcceleration::Steffensen => steffensen_iteration(g, initial, stop_cond),
+    }
+}
+
+fn plain_iteration<T, F>(
+    g: &F,
+    initial: T,
+    stop_cond: &IterStopCondition<T>,
+) -> FixedPointSolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: Dim1Fn<T>,
+{
+    let mut root = initial;
+    let mut last_step: Option<T> = None;
+    let mut contraction_rate = None;
+    let mut iter_count = 0;
+
+    let result = |root, iter_count, stop_reason, contraction_rate| FixedPointSolveResult {
+        solve_result: SolveResult {
+            root,
+            iter_count,
+            stop_reason,
+        },
+        contraction_rate,
+    };
+
+    loop {
+        if iter_count >= stop_cond.open_iter_count_limit() {
+            return result(
+                root,
+                iter_count,
+                StopReason::IterCountLimit,
+                contraction_rate,
+            );
+        }
+
+        let next = g.eval(root);
+        if !next.is_finite() {
+            return result(root, iter_count, StopReason::Diverging, contraction_rate);
+        }
+
+        let step = next - root;
+        if step.abs() <= stop_cond.y_tolorency() {
+            return result(root, iter_count, StopReason::TolorencyY, contraction_rate);
+        }
+
+        if let Some(last_step) = last_step {
+            if last_step != T::ZERO {
+                contraction_rate = Some(step.abs() / last_step.abs());
+            }
+        }
+
+        if step.abs() <= stop_cond.x_tolorency() * next.abs().max(T::ONE) {
+            return result(next, iter_count, StopReason::TolorencyX, contraction_rate);
+        }
+
+        if let Some(rate) = contraction_rate {
+            if rate >= T::ONE {
+                return result(root, iter_count, StopReason::Diverging, contraction_rate);
+            }
+        }
+
+        last_step = Some(step);
+        root = next;
+        iter_count += 1;
+    }
+}
+
+fn steffensen_iteration<T, F>(
+    g: &F,
+    initial: T,
+    stop_cond: &IterStopCondition<T>,
+) -> FixedPointSolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: Dim1Fn<T>,
+{
+    let mut root = initial;
+    let mut contraction_rate = None;
+    let mut iter_count = 0;
+
+    let result = |root, iter_count, stop_reason, contraction_rate| FixedPointSolveResult {
+        solve_result: SolveResult {
+            root,
+            iter_count,
+            stop_reason,
+        },
+        contraction_rate,
+    };
+
+    loop {
+        if iter_count >= stop_cond.open_iter_count_limit() {
+            return result(
+                root,
+                iter_count,
+                StopReason::IterCountLimit,
+                contraction_rate,
+            );
+        }
+
+        let x1 = g.eval(root);
+        let x2 = g.eval(x1);
+        if !x1.is_finite() || !x2.is_finite() {
+            return result(root, iter_count, StopReason::Diverging, contraction_rate);
+        }
+
+        let step1 = x1 - root;
+        if step1.abs() <= stop_cond.y_tolorency() {
+            return result(root, iter_count, StopReason::TolorencyY, contraction_rate);
+        }
+
+        let step2 = x2 - x1;
+        contraction_rate = Some(step2.abs() / step1.abs());
+
+        // g(x) - x is locally flat, Aitken extrapolation is undefined
+        let denom = step2 - step1;
+        if denom == T::ZERO {
+            return result(
+                root,
+                iter_count,
+                StopReason::ZeroDerivative,
+                contraction_rate,
+            );
+        }
+
+        let next = root - step1 * step1 / denom;
+        if !next.is_finite() {
+            return result(root, iter_count, StopReason::Diverging, contraction_rate);
+        }
+
+        if (next - root).abs() <= stop_cond.x_tolorency() * next.abs().max(T::ONE) {
+            return result(next, iter_count, StopReason::TolorencyX, contraction_rate);
+        }
+
+        root = next;
+        iter_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT,
+        dim1_func::polynomial::Polynomial,
+    };
+
+    struct Cos;
+
+    impl Dim1Fn<f64> for Cos {
+        fn eval(&self, x: f64) -> f64 {
+            x.cos()
+        }
+    }
+
+    struct Sin;
+
+    impl Dim1Fn<f64> for Sin {
+        fn eval(&self, x: f64) -> f64 {
+            x.sin()
+        }
+    }
+
+    #[test]
+    fn test_meet_tolorency_x() {
+        let stop_cond = IterStopCondition::new().with_x_tolorency(1e-12);
+        let result = fixed_point_solve(&Cos, 0.0, &stop_cond, FixedPointAcceleration::None);
+        println!("iter count = {}", result.iter_count());
+        assert!(result.stop_reason() == StopReason::TolorencyX);
+        let root = result.root();
+        assert!(root.cos().abs_diff_eq(&root, 1e-11), "root = {root}");
+        // |g'(r)| = |sin(r)|
+        assert!(result
+            .contraction_rate()
+            .unwrap()
+            .abs_diff_eq(&root.sin(), 1e-2));
+    }
+
+    #[test]
+    fn test_diverging() {
+        let g = Polynomial::new(2.0).with_coefficients(&[1.0]);
+        let result = fixed_point_solve(
+            &g,
+            0.0,
+            &IterStopCondition::new(),
+            FixedPointAcceleration::None,
+        );
+        assert!(result.stop_reason() == StopReason::Diverging);
+        assert!(result.iter_count() == 1);
+        assert!(result.contraction_rate() == Some(2.0));
+    }
+
+    #[test]
+    fn test_slow_contraction() {
+        // sin(x) contracts towards 0 with a rate tending to 1, about 1 / sqrt(k) after k
+        // steps, far from the default tolorency in any reasonable time
+        let result = fixed_point_solve(
+            &Sin,
+            1.0,
+            &IterStopCondition::new(),
+            FixedPointAcceleration::None,
+        );
+        println!("stopped at {}", result.root());
+        assert!(result.stop_reason() == StopReason::IterCountLimit);
+        assert!(result.iter_count() == DEFAULT_OPEN_ITER_COUNT_LIMIT);
+        assert!(result.contraction_rate().unwrap() < 1.0);
+    }
+
+    #[test]
+    fn test_steffensen() {
+        let stop_cond = IterStopCondition::new().with_x_tolorency(1e-12);
+        let plain = fixed_point_solve(&Cos, 0.0, &stop_cond, FixedPointAcceleration::None);
+        let accelerated =
+            fixed_point_solve(&Cos, 0.0, &stop_cond, FixedPointAcceleration::Steffensen);
+        println!(
+            "iter count: plain = {}, steffensen = {}",
+            plain.iter_count(),
+            accelerated.iter_count()
+        );
+        assert!(accelerated.stop_reason() == StopReason::TolorencyX);
+        assert!(accelerated.iter_count() < plain.iter_count());
+        assert!(accelerated.root().abs_diff_eq(&plain.root(), 1e-11));
+
+        // not contractive, but steffensen still finds the fixed point -1
+        let g = Polynomial::new(2.0).with_coefficients(&[1.0]);
+        let result = fixed_point_solve(
+            &g,
+            0.0,
+            &IterStopCondition::new(),
+            FixedPointAcceleration::Steffensen,
+        );
+        assert!(result.root() == -1.0);
+    }
+}
@@ -0,0 +1,212 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    dim1_func::Dim1Fn,
+    float_traits::{Abs, Finite, FloatConst, MaxMin},
+    interpolation::newton::newton_interpolate,
+};
+
+use super::find_root::{IterStopCondition, SolveResult, StopReason};
+
+/// Root estimate `P(0)` of the polynomial `x = P(y)` through the points `(ys[i], xs[i])`,
+/// the inverse interpolation of order `xs.len() - 1`.
+///
+/// Panics if two of `ys` are equal.
+pub fn inverse_interpolation_step<T>(xs: &[T], ys: &[T]) -> T
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialEq
+        + Copy,
+{
+    newton_interpolate(ys, xs).nest_mul(T::ZERO)
+}
+
+/// Inverse interpolation iteration: the next iterate is [`inverse_interpolation_step`] of
+/// the last `initial.len()` iterates, which replaces the oldest one. Two initial points
+/// give the secant method, three the inverse quadratic interpolation.
+///
+/// Stops with [`StopReason::ZeroDerivative`] when two iterates have the same function
+/// value, where the inverse interpolant does not exist. Without an iteration limit in
+/// `stop_cond`, stops after
+/// [`DEFAULT_OPEN_ITER_COUNT_LIMIT`](super::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT)
+/// iterations.
+pub fn inverse_interpolation_solve<T, F>(
+    func: &F,
+    initial: &[T],
+    stop_cond: &IterStopCondition<T>,
+) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: Dim1Fn<T>,
+{
+    assert!(
+        initial.len() >= 2,
+        "inverse interpolation needs at least 2 initial points"
+    );
+
+    let mut xs = initial.to_vec();
+    let mut ys: Vec<T> = xs.iter().map(|x| func.eval(*x)).collect();
+    let mut iter_count = 0;
+
+    loop {
+        let (root, y) = (xs[xs.len() - 1], ys[ys.len() - 1]);
+
+        if iter_count >= stop_cond.open_iter_count_limit() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::IterCountLimit,
+            };
+        }
+
+        if !y.is_finite() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if y.abs() <= stop_cond.y_tolorency() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::TolorencyY,
+            };
+        }
+
+        if (1..ys.len()).any(|i| ys[..i].contains(&ys[i])) {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::ZeroDerivative,
+            };
+        }
+
+        let next = inverse_interpolation_step(&xs, &ys);
+        if !next.is_finite() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if (next - root).abs() <= stop_cond.x_tolorency() * next.abs().max(T::ONE) {
+            return SolveResult {
+                root: next,
+                iter_count,
+                stop_reason: StopReason::TolorencyX,
+            };
+        }
+
+        xs.remove(0);
+        ys.remove(0);
+        xs.push(next);
+        ys.push(func.eval(next));
+        iter_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::dim1_equation_solve::{
+        brent::inverse_quad_interpolation, find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT,
+        secant::secant_solve,
+    };
+
+    struct CosMinusX;
+
+    impl Dim1Fn<f64> for CosMinusX {
+        fn eval(&self, x: f64) -> f64 {
+            x.cos() - x
+        }
+    }
+
+    const ROOT: f64 = 0.739_085_133_215_160_6;
+
+    #[test]
+    fn test_step() {
+        let (xs, ys) = ([0.0, 0.5, 1.0], [1.0, 0.377_582_561_9, -0.459_697_694_1]);
+        let step = inverse_interpolation_step(&xs, &ys);
+        let brent = inverse_quad_interpolation((xs[0], xs[1], xs[2]), (ys[0], ys[1], ys[2]));
+        assert!(step.abs_diff_eq(&brent, 1e-15));
+
+        // a linear inverse is exact
+        assert!(inverse_interpolation_step(&[1.0, 2.0, 4.0], &[3.0, 1.0, -3.0]) == 2.5);
+    }
+
+    #[test]
+    fn test_orders() {
+        let stop_cond = IterStopCondition::new();
+        for order in 1..=4 {
+            let initial: Vec<f64> = (0..=order).map(|i| i as f64 * 0.3).collect();
+            let result = inverse_interpolation_solve(&CosMinusX, &initial, &stop_cond);
+            println!(
+                "order {order}: root = {}, iter count = {}, {:?}",
+                result.root(),
+                result.iter_count(),
+                result.stop_reason()
+            );
+            assert!(result.root().abs_diff_eq(&ROOT, 1e-14));
+        }
+
+        // order 1 is the secant method
+        let inverse = inverse_interpolation_solve(&CosMinusX, &[0.0, 1.0], &stop_cond);
+        let secant = secant_solve(&CosMinusX, 0.0, 1.0, &stop_cond);
+        assert!(inverse.iter_count() == secant.iter_count());
+    }
+
+    #[test]
+    fn test_no_root() {
+        // x^2 + 1 has no real root, the iterates wander without end
+        struct SquarePlusOne;
+
+        impl Dim1Fn<f64> for SquarePlusOne {
+            fn eval(&self, x: f64) -> f64 {
+                x * x + 1.0
+            }
+        }
+
+        let result = inverse_interpolation_solve(
+            &SquarePlusOne,
+            &[0.5, 2.0, 3.0],
+            &IterStopCondition::new(),
+        );
+        println!("stopped at {} by {:?}", result.root(), result.stop_reason());
+        assert!(result.stop_reason() == StopReason::IterCountLimit);
+        assert!(result.iter_count() == DEFAULT_OPEN_ITER_COUNT_LIMIT);
+    }
+
+    #[test]
+    fn test_equal_values() {
+        struct Square;
+
+        impl Dim1Fn<f64> for Square {
+            fn eval(&self, x: f64) -> f64 {
+                x * x - 2.0
+            }
+        }
+
+        let result =
+            inverse_interpolation_solve(&Square, &[-1.0, 1.0, 3.0], &IterStopCondition::new());
+        assert!(result.stop_reason() == StopReason::ZeroDerivative);
+    }
+}
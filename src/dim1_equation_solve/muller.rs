@@ -0,0 +1,217 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    complex::Complex,
+    dim1_func::Dim1Fn,
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64, MaxMin, Sqrt},
+};
+
+use super::find_root::{IterStopCondition, SolveResult, StopReason};
+
+/// Muller's method over the complex numbers: the next iterate is the root, closest to the
+/// last iterate, of the parabola through the last three points. Complex roots are reached
+/// even from real starting points.
+///
+/// Tolorencies of `stop_cond` apply to the moduli `|x_{k+1} - x_k|` and `|f(x_k)|`.
+/// Without an iteration limit, stops after
+/// [`DEFAULT_OPEN_ITER_COUNT_LIMIT`](super::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT)
+/// iterations.
+pub fn muller_solve<T, F>(
+    func: &F,
+    [x0, x1, x2]: [Complex<T>; 3],
+    stop_cond: &IterStopCondition<T>,
+) -> SolveResult<Complex<T>>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Finite,
+    F: Dim1Fn<Complex<T>>,
+{
+    let is_finite = |z: Complex<T>| z.re.is_finite() && z.im.is_finite();
+
+    let mut points = [x0, x1, x2];
+    let mut values = points.map(|x| func.eval(x));
+    let mut iter_count = 0;
+
+    loop {
+        let (root, y) = (points[2], values[2]);
+
+        if iter_count >= stop_cond.open_iter_count_limit() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::IterCountLimit,
+            };
+        }
+
+        if !is_finite(y) {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if y.norm() <= stop_cond.y_tolorency() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::TolorencyY,
+            };
+        }
+
+        // p(x) = a (x - x2)^2 + b (x - x2) + c through the three points
+        let (h1, h2) = (points[1] - points[0], points[2] - points[1]);
+        let d1 = (values[1] - values[0]) / h1;
+        let d2 = (values[2] - values[1]) / h2;
+        let a = (d2 - d1) / (h1 + h2);
+        let b = a * h2 + d2;
+        let discriminant = (b * b - a * y * Complex::from_real(fl!(4.0))).sqrt();
+        let (plus, minus) = (b + discriminant, b - discriminant);
+        let denominator = if plus.norm() >= minus.norm() {
+            plus
+        } else {
+            minus
+        };
+        if denominator == Complex::from_real(T::ZERO) {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::ZeroDerivative,
+            };
+        }
+
+        let step = -(y * Complex::from_real(fl!(2.0))) / denominator;
+        let next = root + step;
+        if !is_finite(next) {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if step.norm() <= stop_cond.x_tolorency() * next.norm().max(T::ONE) {
+            return SolveResult {
+                root: next,
+                iter_count,
+                stop_reason: StopReason::TolorencyX,
+            };
+        }
+
+        points = [points[1], points[2], next];
+        values = [values[1], values[2], func.eval(next)];
+        iter_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT,
+        dim1_func::polynomial::Polynomial,
+    };
+
+    fn real(x: f64) -> Complex<f64> {
+        Complex::from_real(x)
+    }
+
+    #[test]
+    fn test_complex_root_from_real_start() {
+        // x^2 + 1
+        let p = Polynomial::new(1.0).with_coefficients(&[1.0, 0.0]);
+        let result = muller_solve(
+            &p,
+            [real(0.5), real(1.0), real(1.5)],
+            &IterStopCondition::new(),
+        );
+        println!(
+            "root = {}, iter count = {}, {:?}",
+            result.root(),
+            result.iter_count(),
+            result.stop_reason()
+        );
+        let root = result.root();
+        assert!(root.re.abs_diff_eq(&0.0, 1e-14));
+        assert!(root.im.abs().abs_diff_eq(&1.0, 1e-14));
+    }
+
+    #[test]
+    fn test_all_roots_of_cubic() {
+        // x^3 + x - 10 = (x - 2)(x^2 + 2x + 5), roots 2 and -1 ± 2i
+        let p = Polynomial::new(1.0).with_coefficients(&[-10.0, 1.0, 0.0]);
+        let stop_cond = IterStopCondition::new();
+        for (start, expected) in [
+            (real(3.0), Complex::new(2.0, 0.0)),
+            (Complex::new(-1.0, 1.5), Complex::new(-1.0, 2.0)),
+            (Complex::new(-1.0, -1.5), Complex::new(-1.0, -2.0)),
+        ] {
+            let offset = Complex::new(0.1, 0.0);
+            let result = muller_solve(&p, [start - offset, start, start + offset], &stop_cond);
+            assert!(
+                (result.root() - expected).norm().abs_diff_eq(&0.0, 1e-13),
+                "root = {}",
+                result.root()
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_root() {
+        // |z|^2 + 1 vanishes nowhere, the iterates wander without end
+        struct NormSquaredPlusOne;
+
+        impl Dim1Fn<Complex<f64>> for NormSquaredPlusOne {
+            fn eval(&self, z: Complex<f64>) -> Complex<f64> {
+                Complex::from_real(z.norm() * z.norm() + 1.0)
+            }
+        }
+
+        let result = muller_solve(
+            &NormSquaredPlusOne,
+            [real(0.5), real(1.0), real(1.5)],
+            &IterStopCondition::new(),
+        );
+        assert!(result.stop_reason() == StopReason::IterCountLimit);
+        assert!(result.iter_count() == DEFAULT_OPEN_ITER_COUNT_LIMIT);
+    }
+
+    #[test]
+    fn test_non_polynomial() {
+        // e^z = -1 at z = iπ
+        struct ExpPlusOne;
+
+        impl Dim1Fn<Complex<f64>> for ExpPlusOne {
+            fn eval(&self, z: Complex<f64>) -> Complex<f64> {
+                Complex::from_polar(z.re.exp(), z.im) + Complex::from_real(1.0)
+            }
+        }
+
+        let result = muller_solve(
+            &ExpPlusOne,
+            [
+                Complex::new(0.0, 2.5),
+                Complex::new(0.2, 3.0),
+                Complex::new(-0.1, 3.5),
+            ],
+            &IterStopCondition::new(),
+        );
+        assert!((result.root() - Complex::new(0.0, std::f64::consts::PI))
+            .norm()
+            .abs_diff_eq(&0.0, 1e-14));
+    }
+}
@@ -0,0 +1,165 @@
+use std::ops::{Div, Mul, Sub};
+
+use crate::{
+    dim1_func::Dim1FnWithDerivative,
+    float_traits::{Abs, Finite, FloatConst, MaxMin},
+};
+
+use super::find_root::{IterStopCondition, SolveResult, StopReason};
+
+/// Newton–Raphson iteration `x_{k+1} = x_k - f(x_k) / f'(x_k)` starting from `initial`.
+///
+/// Without an iteration limit in `stop_cond`, stops after
+/// [`DEFAULT_OPEN_ITER_COUNT_LIMIT`](super::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT)
+/// iterations.
+pub fn newton_solve<T, F>(func: &F, initial: T, stop_cond: &IterStopCondition<T>) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: Dim1FnWithDerivative<T>,
+{
+    let mut root = initial;
+    let mut iter_count = 0;
+
+    loop {
+        if iter_count >= stop_cond.open_iter_count_limit() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::IterCountLimit,
+            };
+        }
+
+        let (y, dy) = func.eval_with_derivative(root);
+        if !y.is_finite() || !dy.is_finite() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if y.abs() <= stop_cond.y_tolorency() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::TolorencyY,
+            };
+        }
+
+        if dy == T::ZERO {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::ZeroDerivative,
+            };
+        }
+
+        let next = root - y / dy;
+        if !next.is_finite() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if (next - root).abs() <= stop_cond.x_tolorency() * next.abs().max(T::ONE) {
+            return SolveResult {
+                root: next,
+                iter_count,
+                stop_reason: StopReason::TolorencyX,
+            };
+        }
+
+        root = next;
+        iter_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT,
+        dim1_func::{polynomial::Polynomial, Dim1Fn},
+    };
+
+    #[test]
+    fn test_meet_tolorency_x() {
+        let f = Polynomial::new(1.0).with_coefficients(&[-2.0, 0.0]);
+        let stop_cond = IterStopCondition::new().with_x_tolorency(1e-12);
+        let result = newton_solve(&f, 1.0, &stop_cond);
+        println!("iter count = {}", result.iter_count());
+        assert!(result.stop_reason() == StopReason::TolorencyX);
+        assert!(
+            result.root().abs_diff_eq(&2_f64.sqrt(), 1e-12),
+            "root = {}",
+            result.root()
+        );
+    }
+
+    #[test]
+    fn test_meet_tolorency_y() {
+        let f = Polynomial::new(1.0).with_base_points(&[1.0, -3.0, 4.0]);
+        let stop_cond = IterStopCondition::new().with_y_tolorency(1e-10);
+        let result = newton_solve(&f, 3.0, &stop_cond);
+        assert!(result.stop_reason() == StopReason::TolorencyY);
+        assert!(f.eval(result.root()).abs_diff_eq(&0.0, 1e-10));
+    }
+
+    #[test]
+    fn test_zero_derivative() {
+        let f = Polynomial::new(1.0).with_coefficients(&[-2.0, 0.0]);
+        let result = newton_solve(&f, 0.0, &IterStopCondition::new());
+        assert!(result.stop_reason() == StopReason::ZeroDerivative);
+        assert!(result.iter_count() == 0);
+        assert!(result.root() == 0.0);
+    }
+
+    #[test]
+    fn test_cycle() {
+        // x^3 - 2x + 2 sends Newton's method from 0 to 1 and back forever
+        let f = Polynomial::new(1.0).with_coefficients(&[2.0, -2.0, 0.0]);
+        let result = newton_solve(&f, 0.0, &IterStopCondition::new());
+        assert!(result.stop_reason() == StopReason::IterCountLimit);
+        assert!(result.iter_count() == DEFAULT_OPEN_ITER_COUNT_LIMIT);
+        assert!(result.root() == 0.0 || result.root() == 1.0);
+
+        let stop_cond = IterStopCondition::new().with_iter_count_limit(7);
+        assert!(newton_solve(&f, 0.0, &stop_cond).iter_count() == 7);
+    }
+
+    struct CubeRoot;
+
+    impl Dim1Fn<f64> for CubeRoot {
+        fn eval(&self, x: f64) -> f64 {
+            x.cbrt()
+        }
+    }
+
+    impl Dim1FnWithDerivative<f64> for CubeRoot {
+        fn eval_with_derivative(&self, x: f64) -> (f64, f64) {
+            (x.cbrt(), 1.0 / (3.0 * x.cbrt() * x.cbrt()))
+        }
+    }
+
+    #[test]
+    fn test_diverging() {
+        // newton step of cbrt is x -> -2x, which never converges
+        let result = newton_solve(&CubeRoot, 0.1, &IterStopCondition::new());
+        assert!(result.stop_reason() == StopReason::Diverging);
+        assert!(result.root().is_finite());
+        println!("iter count = {}", result.iter_count());
+    }
+}
@@ -0,0 +1,371 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::{Dim1Fn, Dim1FnWithDerivative},
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64, MaxMin},
+};
+
+use super::find_root::{
+    FindRootError, FindRootProblem, IterStopCondition, SolveResult, StopReason,
+};
+
+/// Safeguarded Newton iteration ("rtsafe"): takes Newton steps inside the bracket of
+/// `problem`, and falls back to bisection when a step leaves the bracket or does not
+/// shrink fast enough
+pub fn rtsafe_solve<T, F>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1FnWithDerivative<T>,
+{
+    debug_assert!(problem.is_valid());
+
+    let mut model = NewtonModel {
+        func: problem.func(),
+        derivative: T::ZERO,
+    };
+    safeguarded_solve(problem, stop_cond, &mut model)
+}
+
+/// Same as [`rtsafe_solve`], but returns an error for an invalid `problem` or `stop_cond`
+/// instead of assuming them valid
+pub fn try_rtsafe_solve<T, F>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+) -> Result<SolveResult<T>, FindRootError>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1FnWithDerivative<T>,
+{
+    problem.validate()?;
+    stop_cond.validate()?;
+    Ok(rtsafe_solve(problem, stop_cond))
+}
+
+/// Safeguarded secant iteration: the same bracket keeping scheme as [`rtsafe_solve`],
+/// but the steps only need function values
+pub fn secant_bisection_solve<T, F>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    debug_assert!(problem.is_valid());
+
+    let range = problem.search_range();
+    let mut model = SecantModel {
+        func: problem.func(),
+        last: (range.start, problem.func_eval(range.start)),
+        prev: None,
+    };
+    safeguarded_solve(problem, stop_cond, &mut model)
+}
+
+/// Same as [`secant_bisection_solve`], but returns an error for an invalid `problem` or `stop_cond`
+/// instead of assuming them valid
+pub fn try_secant_bisection_solve<T, F>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+) -> Result<SolveResult<T>, FindRootError>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+{
+    problem.validate()?;
+    stop_cond.validate()?;
+    Ok(secant_bisection_solve(problem, stop_cond))
+}
+
+/// Source of the fast (unsafe) steps of a safeguarded iteration
+trait StepModel<T> {
+    /// Evaluates `f(x)`, remembering whatever is needed to propose the next step
+    fn eval(&mut self, x: T) -> T;
+
+    /// Proposes the next iterate after `x`, where `f(x) = y` was just evaluated
+    fn propose(&self, x: T, y: T) -> Option<T>;
+}
+
+struct NewtonModel<'a, T, F> {
+    func: &'a F,
+    derivative: T,
+}
+
+impl<T, F> StepModel<T> for NewtonModel<'_, T, F>
+where
+    T: Sub<Output = T> + Div<Output = T> + FloatConst + PartialEq + Copy,
+    F: Dim1FnWithDerivative<T>,
+{
+    fn eval(&mut self, x: T) -> T {
+        let (y, dy) = self.func.eval_with_derivative(x);
+        self.derivative = dy;
+        y
+    }
+
+    fn propose(&self, x: T, y: T) -> Option<T> {
+        if self.derivative == T::ZERO {
+            None
+        } else {
+            Some(x - y / self.derivative)
+        }
+    }
+}
+
+struct SecantModel<'a, T, F> {
+    func: &'a F,
+    last: (T, T),
+    prev: Option<(T, T)>,
+}
+
+impl<T, F> StepModel<T> for SecantModel<'_, T, F>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + PartialEq + Copy,
+    F: Dim1Fn<T>,
+{
+    fn eval(&mut self, x: T) -> T {
+        let y = self.func.eval(x);
+        self.prev = Some(self.last);
+        self.last = (x, y);
+        y
+    }
+
+    fn propose(&self, x: T, y: T) -> Option<T> {
+        let (prev_x, prev_y) = self.prev?;
+        if y == prev_y {
+            None
+        } else {
+            Some(x - y * (x - prev_x) / (y - prev_y))
+        }
+    }
+}
+
+fn safeguarded_solve<T, F, M>(
+    problem: &FindRootProblem<T, F>,
+    stop_cond: &IterStopCondition<T>,
+    model: &mut M,
+) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + FromF64
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: ContinuousFn + Dim1Fn<T>,
+    M: StepModel<T>,
+{
+    let range = problem.search_range();
+    let (mut a, mut b) = (range.start, range.end);
+    let y_a = problem.func_eval(a);
+
+    let mut root = (a + b) / fl!(2.0);
+    let mut root_y = model.eval(root);
+    if y_a * root_y <= T::ZERO {
+        b = root;
+    } else {
+        a = root;
+    }
+
+    // the step before the last one, bisection is forced if a step is not at least twice
+    // smaller than it
+    let mut step_old = range.end - range.start;
+    let mut step = step_old;
+
+    let mut iter_count = 0;
+
+    loop {
+        if let Some(limit) = stop_cond.iter_count_limit() {
+            if iter_count >= limit {
+                return SolveResult {
+                    root,
+                    iter_count,
+                    stop_reason: StopReason::IterCountLimit,
+                };
+            }
+        }
+
+        if root_y.abs() <= stop_cond.y_tolorency() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::TolorencyY,
+            };
+        }
+
+        if b - a <= stop_cond.x_tolorency() * fl!(2.0) * root.abs().max(T::ONE) {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::TolorencyX,
+            };
+        }
+
+        let next = match model.propose(root, root_y) {
+            Some(x)
+                if x.is_finite()
+                    && x > a
+                    && x < b
+                    && (x - root).abs() * fl!(2.0) <= step_old.abs() =>
+            {
+                x
+            }
+            _ => (a + b) / fl!(2.0),
+        };
+
+        if (next - root).abs() <= stop_cond.x_tolorency() * next.abs().max(T::ONE) {
+            return SolveResult {
+                root: next,
+                iter_count,
+                stop_reason: StopReason::TolorencyX,
+            };
+        }
+
+        step_old = step;
+        step = next - root;
+
+        root = next;
+        root_y = model.eval(root);
+        if y_a * root_y <= T::ZERO {
+            b = root;
+        } else {
+            a = root;
+        }
+
+        iter_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::dim1_equation_solve::brent::brent_solve;
+    use crate::dim1_func::polynomial::Polynomial;
+
+    #[test]
+    fn test_rtsafe_meet_tolorency_x() {
+        let problem = FindRootProblem::new(
+            Polynomial::new(1.0).with_coefficients(&[-2.0, 0.0]),
+            0.0..5.0,
+        );
+        let stop_cond = IterStopCondition::new().with_x_tolorency(1e-12);
+        let result = rtsafe_solve(&problem, &stop_cond);
+        println!("iter count = {}", result.iter_count());
+        assert!(result.stop_reason() == StopReason::TolorencyX);
+        assert!(
+            result.root().abs_diff_eq(&2_f64.sqrt(), 1e-12),
+            "root = {}",
+            result.root()
+        );
+    }
+
+    #[test]
+    fn test_rtsafe_stays_in_bracket() {
+        // plain newton from the middle of the range jumps to the far root at 10
+        let f = Polynomial::new(1.0).with_base_points(&[-10.0, 1.0, 10.0]);
+        let problem = FindRootProblem::new(f, 0.0..4.0);
+        let result = rtsafe_solve(&problem, &IterStopCondition::new());
+        assert!(
+            result.root().abs_diff_eq(&1.0, 1e-12),
+            "root = {}",
+            result.root()
+        );
+    }
+
+    #[test]
+    fn test_secant_bisection_meet_tolorency_y() {
+        let f = Polynomial::new(1.0).with_base_points(&[1.0, -3.0, 4.0]);
+        let problem = FindRootProblem::new(f.clone(), 2.0..30.0);
+        let stop_cond = IterStopCondition::new().with_y_tolorency(1e-10);
+        let result = secant_bisection_solve(&problem, &stop_cond);
+        assert!(result.stop_reason() == StopReason::TolorencyY);
+        assert!(f.eval(result.root()).abs_diff_eq(&0.0, 1e-10));
+    }
+
+    #[test]
+    fn test_compare_with_brent() {
+        let problems = [
+            FindRootProblem::new(
+                Polynomial::default().with_coefficients(&[2.0, -3.0]),
+                1.5..300.0,
+            ),
+            FindRootProblem::new(
+                Polynomial::new(-1e-4).with_coefficients(&[1.0]),
+                9000.0..20000.0,
+            ),
+            FindRootProblem::new(
+                Polynomial::default().with_base_points(&[0.3, 0.3, 0.3, 0.7]),
+                0.5..1.0,
+            ),
+        ];
+        let stop_cond = IterStopCondition::new()
+            .with_x_tolorency(1e-12)
+            .with_iter_count_limit(200);
+
+        for problem in problems.iter() {
+            let brent = brent_solve(problem, &stop_cond);
+            let rtsafe = rtsafe_solve(problem, &stop_cond);
+            let secant = secant_bisection_solve(problem, &stop_cond);
+            println!(
+                "iter count: brent = {}, rtsafe = {}, secant bisection = {}",
+                brent.iter_count(),
+                rtsafe.iter_count(),
+                secant.iter_count()
+            );
+            let tol = 1e-9 * brent.root().abs().max(1.0);
+            assert!(rtsafe.root().abs_diff_eq(&brent.root(), tol));
+            assert!(secant.root().abs_diff_eq(&brent.root(), tol));
+        }
+    }
+}
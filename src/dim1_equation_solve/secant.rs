@@ -0,0 +1,146 @@
+use std::ops::{Div, Mul, Sub};
+
+use crate::{
+    dim1_func::Dim1Fn,
+    float_traits::{Abs, Finite, FloatConst, MaxMin},
+};
+
+use super::find_root::{IterStopCondition, SolveResult, StopReason};
+
+/// Secant iteration `x_{k+1} = x_k - f(x_k) (x_k - x_{k-1}) / (f(x_k) - f(x_{k-1}))`
+/// starting from the two points `x0` and `x1`.
+///
+/// Without an iteration limit in `stop_cond`, stops after
+/// [`DEFAULT_OPEN_ITER_COUNT_LIMIT`](super::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT)
+/// iterations.
+pub fn secant_solve<T, F>(
+    func: &F,
+    x0: T,
+    x1: T,
+    stop_cond: &IterStopCondition<T>,
+) -> SolveResult<T>
+where
+    T: PartialOrd
+        + MaxMin
+        + Copy
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + Abs
+        + Finite,
+    F: Dim1Fn<T>,
+{
+    let (mut prev, mut prev_y) = (x0, func.eval(x0));
+    let mut root = x1;
+    let mut iter_count = 0;
+
+    loop {
+        if iter_count >= stop_cond.open_iter_count_limit() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::IterCountLimit,
+            };
+        }
+
+        let y = func.eval(root);
+        if !y.is_finite() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if y.abs() <= stop_cond.y_tolorency() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::TolorencyY,
+            };
+        }
+
+        if y == prev_y {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::ZeroDerivative,
+            };
+        }
+
+        let next = root - y * (root - prev) / (y - prev_y);
+        if !next.is_finite() {
+            return SolveResult {
+                root,
+                iter_count,
+                stop_reason: StopReason::Diverging,
+            };
+        }
+
+        if (next - root).abs() <= stop_cond.x_tolorency() * next.abs().max(T::ONE) {
+            return SolveResult {
+                root: next,
+                iter_count,
+                stop_reason: StopReason::TolorencyX,
+            };
+        }
+
+        (prev, prev_y) = (root, y);
+        root = next;
+        iter_count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::find_root::DEFAULT_OPEN_ITER_COUNT_LIMIT,
+        dim1_func::polynomial::Polynomial,
+    };
+
+    #[test]
+    fn test_meet_tolorency_x() {
+        let f = Polynomial::new(1.0).with_coefficients(&[-2.0, 0.0]);
+        let stop_cond = IterStopCondition::new().with_x_tolorency(1e-12);
+        let result = secant_solve(&f, 1.0, 2.0, &stop_cond);
+        println!("iter count = {}", result.iter_count());
+        assert!(result.stop_reason() == StopReason::TolorencyX);
+        assert!(
+            result.root().abs_diff_eq(&2_f64.sqrt(), 1e-12),
+            "root = {}",
+            result.root()
+        );
+    }
+
+    #[test]
+    fn test_meet_iter_count_limit() {
+        let f = Polynomial::new(1.0).with_base_points(&[1.0, -3.0, 4.0]);
+        let stop_cond = IterStopCondition::new().with_iter_count_limit(2);
+        let result = secant_solve(&f, 10.0, 9.0, &stop_cond);
+        assert!(result.stop_reason() == StopReason::IterCountLimit);
+        assert!(result.iter_count() == 2);
+    }
+
+    #[test]
+    fn test_no_root() {
+        // x^2 + 1 has no real root, the secants wander without end
+        let f = Polynomial::new(1.0).with_coefficients(&[1.0, 0.0]);
+        let result = secant_solve(&f, 0.5, 2.0, &IterStopCondition::new());
+        println!("stopped at {} by {:?}", result.root(), result.stop_reason());
+        assert!(result.stop_reason() == StopReason::IterCountLimit);
+        assert!(result.iter_count() == DEFAULT_OPEN_ITER_COUNT_LIMIT);
+    }
+
+    #[test]
+    fn test_flat_secant() {
+        let f = Polynomial::new(1.0).with_coefficients(&[-2.0, 0.0]);
+        let result = secant_solve(&f, -1.0, 1.0, &IterStopCondition::new());
+        assert!(result.stop_reason() == StopReason::ZeroDerivative);
+        assert!(result.iter_count() == 0);
+    }
+}
@@ -3,3 +3,15 @@ pub mod polynomial;
 pub trait Dim1Fn<T> {
     fn eval(&self, x: T) -> T;
 }
+
+impl<T, F: Dim1Fn<T> + ?Sized> Dim1Fn<T> for &F {
+    fn eval(&self, x: T) -> T {
+        (**self).eval(x)
+    }
+}
+
+/// Functions whose first derivative can be evaluated together with the function value
+pub trait Dim1FnWithDerivative<T>: Dim1Fn<T> {
+    /// Returns `(f(x), f'(x))`
+    fn eval_with_derivative(&self, x: T) -> (T, T);
+}
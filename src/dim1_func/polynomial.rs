@@ -1,14 +1,20 @@
+mod arithmetic;
+mod calculus;
+mod conversion;
+pub mod roots;
+
 use std::{
     fmt::Display,
     ops::{Add, Mul, Sub},
 };
 
 use crate::{
+    complex::Complex,
     continuous_func::ContinuousFn,
     float_traits::{Abs, FloatConst},
 };
 
-use super::Dim1Fn;
+use super::{Dim1Fn, Dim1FnWithDerivative};
 
 #[derive(Clone, Debug)]
 pub struct Polynomial<T> {
@@ -101,6 +107,94 @@ where
     }
 }
 
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + Copy,
+{
+    /// Nested multiplication at a complex argument `z`
+    pub fn nest_mul_complex(&self, z: Complex<T>) -> Complex<T> {
+        let mut y = Complex::from_real(self.leading_coe);
+
+        for i in (0..self.degree()).rev() {
+            let factor = match &self.base_points {
+                Some(b) => z - Complex::from_real(b[i]),
+                None => z,
+            };
+            let c = match &self.coefficients {
+                Some(c) => Complex::from_real(c[i]),
+                None => Complex::from_real(T::ZERO),
+            };
+            y = y * factor + c;
+        }
+
+        y
+    }
+}
+
+impl<T: FloatConst + PartialEq + Copy> Polynomial<T> {
+    /// Builds the monomial form `c[0] + c[1] x + ... + c[n] x^n`, dropping zero
+    /// coefficients of the highest orders
+    pub fn from_monomial_coefficients(coefficients: &[T]) -> Self {
+        let mut len = coefficients.len();
+        while len > 1 && coefficients[len - 1] == T::ZERO {
+            len -= 1;
+        }
+        match len {
+            0 => Polynomial::new(T::ZERO),
+            _ => Polynomial::new(coefficients[len - 1]).with_coefficients(&coefficients[..len - 1]),
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + Copy,
+{
+    /// Coefficients in the monomial basis, from the constant term up to the leading one
+    fn monomial_coefficients(&self) -> Vec<T> {
+        let Some(bps) = &self.base_points else {
+            let mut coes = self.coefficients.clone().unwrap_or_default();
+            coes.push(self.leading_coe);
+            return coes;
+        };
+
+        let mut coes = vec![self.leading_coe];
+        for i in (0..bps.len()).rev() {
+            // multiply by (x - bps[i])
+            coes.insert(0, T::ZERO);
+            for j in 0..(coes.len() - 1) {
+                coes[j] = coes[j] - bps[i] * coes[j + 1];
+            }
+            if let Some(c) = &self.coefficients {
+                coes[0] = coes[0] + c[i];
+            }
+        }
+        coes
+    }
+
+    /// Nested multiplication that also accumulates the derivative, returns `(p(x), p'(x))`
+    pub fn nest_mul_with_derivative(&self, x: T) -> (T, T) {
+        let mut y: T = self.leading_coe;
+        let mut dy: T = T::ZERO;
+
+        let degree = self.degree();
+        for i in (0..degree).rev() {
+            let factor = match &self.base_points {
+                Some(b) => x - b[i],
+                None => x,
+            };
+            let c = match &self.coefficients {
+                Some(c) => c[i],
+                None => T::ZERO,
+            };
+            dy = dy * factor + y;
+            y = y * factor + c;
+        }
+
+        (y, dy)
+    }
+}
+
 impl<T> Dim1Fn<T> for Polynomial<T>
 where
     T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
@@ -110,6 +204,24 @@ where
     }
 }
 
+impl<T> Dim1Fn<Complex<T>> for Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + Copy,
+{
+    fn eval(&self, z: Complex<T>) -> Complex<T> {
+        self.nest_mul_complex(z)
+    }
+}
+
+impl<T> Dim1FnWithDerivative<T> for Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + Copy,
+{
+    fn eval_with_derivative(&self, x: T) -> (T, T) {
+        self.nest_mul_with_derivative(x)
+    }
+}
+
 impl<T> ContinuousFn for Polynomial<T> where Polynomial<T>: Dim1Fn<T> {}
 
 impl<T> Display for Polynomial<T>
@@ -193,4 +305,44 @@ mod tests {
             .with_base_points(&[0.0, 2.0, 3.0]);
         assert!(p.nest_mul(1.0) == 0.0)
     }
+
+    #[test]
+    fn test_nest_mul_with_derivative() {
+        // p(x) = 2x^4 + 3x^3 - 3x^2 + 5x - 1, p'(x) = 8x^3 + 9x^2 - 6x + 5
+        let p = Polynomial::new(2.0).with_coefficients(&[-1.0, 5.0, -3.0, 3.0]);
+        assert!(p.nest_mul_with_derivative(0.0) == (-1.0, 5.0));
+        assert!(p.nest_mul_with_derivative(1.0) == (6.0, 16.0));
+        assert!(p.nest_mul_with_derivative(-2.0) == (-15.0, -11.0));
+
+        // p(x) = -0.5 x (x - 2) (x - 3), p'(x) = -1.5x^2 + 5x - 3
+        let p = Polynomial::new(-0.5).with_base_points(&[0.0, 2.0, 3.0]);
+        assert!(p.nest_mul_with_derivative(1.0) == (-1.0, 0.5));
+
+        let p = Polynomial::new(-0.5)
+            .with_coefficients(&[1.0, 0.5, 0.5])
+            .with_base_points(&[0.0, 2.0, 3.0]);
+        let (y, dy) = p.nest_mul_with_derivative(1.0);
+        assert!(y == p.nest_mul(1.0));
+        assert!(dy == 1.0);
+    }
+
+    #[test]
+    fn test_nest_mul_complex() {
+        // x^2 + 1 vanishes at i
+        let z = Complex::new(0.0, 1.0);
+        let p = Polynomial::new(1.0).with_coefficients(&[1.0, 0.0]);
+        assert!(p.nest_mul_complex(z) == Complex::new(0.0, 0.0));
+        // 2 + (x - 1) in Newton form
+        let p = Polynomial::new(1.0)
+            .with_coefficients(&[2.0])
+            .with_base_points(&[1.0]);
+        assert!(p.nest_mul_complex(z) == Complex::new(1.0, 1.0));
+
+        // a real argument gives the real value
+        let p = Polynomial::new(-0.5)
+            .with_coefficients(&[1.0, 0.5, 0.5])
+            .with_base_points(&[0.0, 2.0, 3.0]);
+        let y: Complex<f64> = p.eval(Complex::from_real(1.5));
+        assert!(y == Complex::from_real(p.nest_mul(1.5)));
+    }
 }
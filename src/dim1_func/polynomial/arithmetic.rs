@@ -0,0 +1,264 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::float_traits::FloatConst;
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + PartialEq + Copy,
+{
+    /// `k * p(x)` in monomial form
+    pub fn scale(&self, k: T) -> Self {
+        let coes: Vec<T> = self
+            .monomial_coefficients()
+            .into_iter()
+            .map(|c| c * k)
+            .collect();
+        Self::from_monomial_coefficients(&coes)
+    }
+
+    /// `p(q(x))` in monomial form, where `p` is `self` and `q` is `inner`
+    pub fn compose(&self, inner: &Polynomial<T>) -> Self {
+        let coes = self.monomial_coefficients();
+        let mut result = Polynomial::new(coes[coes.len() - 1]);
+        for c in coes.iter().rev().skip(1) {
+            result = &(&result * inner) + &Polynomial::new(*c);
+        }
+        result
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialEq
+        + Copy,
+{
+    /// Long division, returns `(quotient, remainder)` in monomial form with
+    /// `self = quotient * divisor + remainder` and `deg(remainder) < deg(divisor)`.
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> (Self, Self) {
+        let divisor = Self::from_monomial_coefficients(&divisor.monomial_coefficients());
+        assert!(
+            divisor.leading_coe != T::ZERO,
+            "division by zero polynomial"
+        );
+        let d = divisor.monomial_coefficients();
+        let mut r =
+            Self::from_monomial_coefficients(&self.monomial_coefficients()).monomial_coefficients();
+
+        if r.len() < d.len() {
+            return (
+                Polynomial::new(T::ZERO),
+                Self::from_monomial_coefficients(&r),
+            );
+        }
+
+        let mut q = vec![T::ZERO; r.len() - d.len() + 1];
+        for i in (0..q.len()).rev() {
+            let k = r[i + d.len() - 1] / d[d.len() - 1];
+            q[i] = k;
+            for (j, dj) in d.iter().enumerate() {
+                r[i + j] = r[i + j] - k * *dj;
+            }
+            r[i + d.len() - 1] = T::ZERO;
+        }
+        r.truncate((d.len() - 1).max(1));
+
+        (
+            Self::from_monomial_coefficients(&q),
+            Self::from_monomial_coefficients(&r),
+        )
+    }
+}
+
+impl<T> Add for &Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + PartialEq + Copy,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut a, b) = (self.monomial_coefficients(), rhs.monomial_coefficients());
+        if a.len() < b.len() {
+            a.resize(b.len(), T::ZERO);
+        }
+        for (ai, bi) in a.iter_mut().zip(b) {
+            *ai = *ai + bi;
+        }
+        Polynomial::from_monomial_coefficients(&a)
+    }
+}
+
+impl<T> Sub for &Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + PartialEq + Copy,
+{
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (mut a, b) = (self.monomial_coefficients(), rhs.monomial_coefficients());
+        if a.len() < b.len() {
+            a.resize(b.len(), T::ZERO);
+        }
+        for (ai, bi) in a.iter_mut().zip(b) {
+            *ai = *ai - bi;
+        }
+        Polynomial::from_monomial_coefficients(&a)
+    }
+}
+
+impl<T> Mul for &Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + PartialEq + Copy,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.monomial_coefficients(), rhs.monomial_coefficients());
+        let mut c = vec![T::ZERO; a.len() + b.len() - 1];
+        for (i, ai) in a.iter().enumerate() {
+            for (j, bj) in b.iter().enumerate() {
+                c[i + j] = c[i + j] + *ai * *bj;
+            }
+        }
+        Polynomial::from_monomial_coefficients(&c)
+    }
+}
+
+impl<T> Neg for &Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + PartialEq
+        + Copy,
+{
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        self.scale(-T::ONE)
+    }
+}
+
+macro_rules! impl_owned_bin_op {
+    ($op: ident, $method: ident) => {
+        impl<T> $op for Polynomial<T>
+        where
+            T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + PartialEq + Copy,
+        {
+            type Output = Polynomial<T>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_owned_bin_op!(Add, add);
+impl_owned_bin_op!(Sub, sub);
+impl_owned_bin_op!(Mul, mul);
+
+impl<T> Neg for Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + PartialEq
+        + Copy,
+{
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::dim1_func::Dim1Fn;
+
+    const SAMPLES: [f64; 5] = [-2.0, -0.5, 0.0, 1.0, 3.0];
+
+    fn newton_form() -> Polynomial<f64> {
+        Polynomial::new(-0.5)
+            .with_coefficients(&[1.0, 0.5, 0.5])
+            .with_base_points(&[0.0, 2.0, 3.0])
+    }
+
+    fn monomial_form() -> Polynomial<f64> {
+        Polynomial::new(2.0).with_coefficients(&[-1.0, 5.0])
+    }
+
+    #[test]
+    fn test_add_sub_mul() {
+        let (p, q) = (newton_form(), monomial_form());
+        let (sum, diff, prod) = (&p + &q, &p - &q, &p * &q);
+        assert!(sum.base_points.is_none() && sum.degree() == 3);
+        assert!(prod.degree() == 5);
+        for x in SAMPLES {
+            assert!(sum.eval(x).abs_diff_eq(&(p.eval(x) + q.eval(x)), 1e-12));
+            assert!(diff.eval(x).abs_diff_eq(&(p.eval(x) - q.eval(x)), 1e-12));
+            assert!(prod.eval(x).abs_diff_eq(&(p.eval(x) * q.eval(x)), 1e-12));
+            assert!((-p.clone()).eval(x).abs_diff_eq(&-p.eval(x), 1e-12));
+            assert!(p.scale(3.0).eval(x).abs_diff_eq(&(3.0 * p.eval(x)), 1e-12));
+        }
+
+        // leading terms cancel
+        let zero = p.clone() - p;
+        assert!(zero.degree() == 0 && zero.eval(1.5) == 0.0);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let (p, q) = (newton_form(), monomial_form());
+        let (quot, rem) = p.div_rem(&q);
+        assert!(quot.degree() == 1 && rem.degree() <= 1);
+        let back = &(&quot * &q) + &rem;
+        for x in SAMPLES {
+            assert!(back.eval(x).abs_diff_eq(&p.eval(x), 1e-12));
+        }
+
+        // (x^3 - 1) / (x - 1) = x^2 + x + 1
+        let p = Polynomial::new(1.0).with_coefficients(&[-1.0, 0.0, 0.0]);
+        let (quot, rem) = p.div_rem(&Polynomial::new(1.0).with_base_points(&[1.0]));
+        assert!(quot.monomial_coefficients() == vec![1.0, 1.0, 1.0]);
+        assert!(rem.monomial_coefficients() == vec![0.0]);
+
+        let (quot, rem) = q.div_rem(&p);
+        assert!(quot.monomial_coefficients() == vec![0.0]);
+        assert!(rem.monomial_coefficients() == q.monomial_coefficients());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_by_zero() {
+        let zero = Polynomial::new(0.0).with_coefficients(&[0.0]);
+        monomial_form().div_rem(&zero);
+    }
+
+    #[test]
+    fn test_compose() {
+        let (p, q) = (newton_form(), monomial_form());
+        let pq = p.compose(&q);
+        assert!(pq.degree() == 6);
+        for x in SAMPLES {
+            assert!(pq.eval(x).abs_diff_eq(&p.eval(q.eval(x)), 1e-9));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::float_traits::{FloatConst, FromF64};
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialEq
+        + Copy,
+{
+    /// `p'(x)` in monomial form
+    pub fn derivative(&self) -> Self {
+        let coes = self.monomial_coefficients();
+        let d: Vec<T> = coes
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| *c * T::from_f64(i as f64))
+            .collect();
+        Self::from_monomial_coefficients(&d)
+    }
+
+    /// The antiderivative `P(x)` of `p(x)` in monomial form with `P(0) = constant`
+    pub fn antiderivative(&self, constant: T) -> Self {
+        let coes = self.monomial_coefficients();
+        let mut a = vec![constant];
+        for (i, c) in coes.iter().enumerate() {
+            a.push(*c / T::from_f64((i + 1) as f64));
+        }
+        Self::from_monomial_coefficients(&a)
+    }
+
+    /// Definite integral of `p(x)` from `a` to `b`
+    pub fn integrate(&self, a: T, b: T) -> T {
+        let p = self.antiderivative(T::ZERO);
+        p.nest_mul(b) - p.nest_mul(a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::{
+            find_root::{IterStopCondition, StopReason},
+            newton::newton_solve,
+        },
+        dim1_func::{Dim1Fn, Dim1FnWithDerivative},
+    };
+
+    #[test]
+    fn test_derivative() {
+        // 2x^4 + 3x^3 - 3x^2 + 5x - 1
+        let p = Polynomial::new(2.0).with_coefficients(&[-1.0, 5.0, -3.0, 3.0]);
+        let d = p.derivative();
+        assert!(d.monomial_coefficients() == vec![5.0, -6.0, 9.0, 8.0]);
+        assert!(
+            d.derivative()
+                .derivative()
+                .derivative()
+                .monomial_coefficients()
+                == vec![48.0]
+        );
+        assert!(
+            d.derivative()
+                .derivative()
+                .derivative()
+                .derivative()
+                .monomial_coefficients()
+                == vec![0.0]
+        );
+
+        let p = Polynomial::new(-0.5)
+            .with_coefficients(&[1.0, 0.5, 0.5])
+            .with_base_points(&[0.0, 2.0, 3.0]);
+        let d = p.derivative();
+        for x in [-2.0, 0.0, 1.0, 2.5] {
+            assert!(d.eval(x).abs_diff_eq(&p.eval_with_derivative(x).1, 1e-12));
+        }
+
+        // newton on p' finds the critical points of p
+        let result = newton_solve(&d, 3.0, &IterStopCondition::new());
+        assert!(result.stop_reason() == StopReason::TolorencyX);
+        assert!(d.eval(result.root()).abs_diff_eq(&0.0, 1e-12));
+    }
+
+    #[test]
+    fn test_antiderivative_and_integrate() {
+        let p = Polynomial::new(1.0).with_base_points(&[1.0, -1.0]);
+        let a = p.antiderivative(2.0);
+        assert!(a.nest_mul(0.0) == 2.0);
+        assert!(a.derivative().monomial_coefficients() == p.monomial_coefficients());
+
+        // integral of x^2 - 1 over [0, 3] is 9 - 3
+        assert!(p.integrate(0.0, 3.0).abs_diff_eq(&6.0, 1e-12));
+        assert!(p.integrate(3.0, 0.0).abs_diff_eq(&-6.0, 1e-12));
+        assert!(Polynomial::new(4.0).integrate(1.0, 2.5) == 6.0);
+    }
+}
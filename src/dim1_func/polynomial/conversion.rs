@@ -0,0 +1,137 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::float_traits::{Abs, FloatConst, MaxMin};
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + PartialEq + Copy,
+{
+    /// The same polynomial with plain coefficients, zero coefficients of the highest
+    /// orders dropped
+    pub fn to_monomial(&self) -> Self {
+        Self::from_monomial_coefficients(&self.monomial_coefficients())
+    }
+
+    /// The same polynomial in Newton form with base points `points`, computed by
+    /// repeated synthetic division.
+    ///
+    /// Panics if there are fewer points than the degree of the polynomial.
+    pub fn to_newton_form(&self, points: &[T]) -> Self {
+        let mut q = self.to_monomial().monomial_coefficients();
+        assert!(
+            q.len() <= points.len() + 1,
+            "{} base points can not represent a polynomial of degree {}",
+            points.len(),
+            q.len() - 1
+        );
+        q.resize(points.len() + 1, T::ZERO);
+
+        let mut coes = Vec::with_capacity(points.len());
+        for b in points {
+            // q(x) = (x - b) * quotient(x) + q(b)
+            let mut acc = q[q.len() - 1];
+            for k in (0..(q.len() - 1)).rev() {
+                let next = q[k] + acc * *b;
+                q[k] = acc;
+                acc = next;
+            }
+            q.pop();
+            coes.push(acc);
+        }
+
+        Polynomial::new(q[0])
+            .with_coefficients(&coes)
+            .with_base_points(points)
+    }
+
+    /// Taylor form around `center`: Newton form whose base points all equal `center`
+    pub fn shift_center(&self, center: T) -> Self {
+        let n = self.to_monomial().degree();
+        self.to_newton_form(&vec![center; n])
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + FloatConst
+        + PartialOrd
+        + Abs
+        + MaxMin
+        + Copy,
+{
+    /// Compares the monomial coefficients of both polynomials, whatever form they are
+    /// stored in. Coefficients `a` and `b` match if `|a - b| <= tol * max(|a|, |b|, 1)`.
+    pub fn approx_eq(&self, other: &Polynomial<T>, tol: T) -> bool {
+        let (mut a, mut b) = (self.monomial_coefficients(), other.monomial_coefficients());
+        let n = a.len().max(b.len());
+        a.resize(n, T::ZERO);
+        b.resize(n, T::ZERO);
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| (*a - *b).abs() <= tol * a.abs().max(b.abs()).max(T::ONE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dim1_func::Dim1Fn;
+
+    #[test]
+    fn test_newton_form_round_trip() {
+        let p = Polynomial::new(-0.5)
+            .with_coefficients(&[1.0, 0.5, 0.5])
+            .with_base_points(&[0.0, 2.0, 3.0]);
+        let m = p.to_monomial();
+        assert!(m.base_points.is_none());
+        assert!(m.approx_eq(&p, 1e-15));
+
+        let n = m.to_newton_form(&[0.0, 2.0, 3.0]);
+        assert!(n.leading_coe == -0.5);
+        assert!(n.coefficients.as_ref().unwrap() == &vec![1.0, 0.5, 0.5]);
+
+        let n = p.to_newton_form(&[-1.0, 4.0, 0.5, 7.0]);
+        assert!(n.degree() == 4 && n.leading_coe == 0.0);
+        assert!(n.approx_eq(&p, 1e-14));
+        for x in [-2.0, 0.3, 5.0] {
+            assert!((n.eval(x) - p.eval(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_few_base_points() {
+        Polynomial::new(1.0)
+            .with_coefficients(&[1.0, 2.0])
+            .to_newton_form(&[0.0]);
+    }
+
+    #[test]
+    fn test_shift_center() {
+        // x^2 = 1 + 2(x - 1) + (x - 1)^2
+        let p = Polynomial::new(1.0).with_coefficients(&[0.0, 0.0]);
+        let t = p.shift_center(1.0);
+        assert!(t.coefficients.as_ref().unwrap() == &vec![1.0, 2.0]);
+        assert!(t.base_points.as_ref().unwrap() == &vec![1.0, 1.0]);
+        assert!(t.leading_coe == 1.0);
+        assert!(t.approx_eq(&p, 0.0));
+
+        let c = Polynomial::new(3.0).shift_center(5.0);
+        assert!(c.degree() == 0 && c.eval(-1.0) == 3.0);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let p = Polynomial::new(1.0).with_base_points(&[1.0, -1.0]);
+        let q = Polynomial::new(1.0).with_coefficients(&[-1.0 + 1e-10, 0.0]);
+        assert!(p.approx_eq(&q, 1e-9));
+        assert!(!p.approx_eq(&q, 1e-11));
+        assert!(!p.approx_eq(&Polynomial::new(1.0).with_coefficients(&[-1.0]), 1e-9));
+    }
+}
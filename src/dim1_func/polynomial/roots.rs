@@ -0,0 +1,433 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    complex::Complex,
+    float_traits::{fl, Abs, Finite, FloatConst, FromF64, MaxMin, Sqrt, Trig},
+};
+
+use super::Polynomial;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PolynomialRoot<T> {
+    value: Complex<T>,
+    multiplicity: usize,
+}
+
+impl<T: Copy> PolynomialRoot<T> {
+    pub fn value(&self) -> Complex<T> {
+        self.value
+    }
+
+    pub fn multiplicity(&self) -> usize {
+        self.multiplicity
+    }
+}
+
+const ABERTH_ITER_LIMIT: usize = 500;
+const POLISH_ITER_LIMIT: usize = 8;
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Trig
+        + Finite
+        + Copy,
+{
+    /// All complex roots, computed by Aberth–Ehrlich iteration on the monomial form.
+    ///
+    /// The approximations are polished by Newton steps, then those closer than
+    /// `EPSILON^(1/4)` relatively are candidates for one multiple root. They are merged,
+    /// with their centroid as value and their count as multiplicity, only if their spread
+    /// is within what rounding errors make of an `m`-fold root, see
+    /// [`Polynomial::roots_with_tolorency`]. Close but distinct roots are kept apart.
+    /// A zero polynomial or a non-zero constant has no roots.
+    pub fn roots(&self) -> Vec<PolynomialRoot<T>> {
+        self.roots_with_tolorency(T::EPSILON.sqrt().sqrt())
+    }
+
+    /// Same as [`Polynomial::roots`], with the relative distance under which root
+    /// approximations are candidates for one multiple root given by `cluster_tol`.
+    ///
+    /// Near an `m`-fold root `c`, `p(z) ≈ t_m (z - c)^m` with `t_m = p^(m)(c) / m!`, and
+    /// the evaluation error of `p` is about `n ε Σ |a_j| |c|^j`, so the approximations
+    /// scatter over a radius `ρ` with `ρ^m |t_m|` of the same order. A cluster is merged
+    /// if `ρ^m |t_m| <= 8^m n ε Σ |a_j| |c|^j`.
+    pub fn roots_with_tolorency(&self, cluster_tol: T) -> Vec<PolynomialRoot<T>> {
+        let mut coes = self.monomial_coefficients();
+        while coes.len() > 1 && coes[coes.len() - 1] == T::ZERO {
+            coes.pop();
+        }
+        if coes.len() <= 1 {
+            return Vec::default();
+        }
+
+        let mut roots = Vec::default();
+
+        let zero_count = coes.iter().take_while(|c| **c == T::ZERO).count();
+        if zero_count > 0 {
+            roots.push(PolynomialRoot {
+                value: Complex::from_real(T::ZERO),
+                multiplicity: zero_count,
+            });
+            coes.drain(0..zero_count);
+        }
+
+        let lead = coes[coes.len() - 1];
+        let monic: Vec<T> = coes.iter().map(|c| *c / lead).collect();
+
+        // (raw approximation, polished one)
+        let mut approximations: Vec<(Complex<T>, Complex<T>)> = aberth(&monic)
+            .into_iter()
+            .map(|z| (z, newton_polish(&monic, z)))
+            .collect();
+        approximations.sort_by(|(_, z0), (_, z1)| {
+            (z0.re, z0.im)
+                .partial_cmp(&(z1.re, z1.im))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut clusters: Vec<Vec<(Complex<T>, Complex<T>)>> = Vec::default();
+        for z in approximations {
+            let cluster = clusters.iter_mut().find(|cluster| {
+                let c = centroid(&polished(cluster));
+                (z.1 - c).norm() <= cluster_tol * c.norm().max(T::ONE)
+            });
+            match cluster {
+                Some(cluster) => cluster.push(z),
+                None => clusters.push(vec![z]),
+            }
+        }
+
+        for cluster in clusters {
+            // the errors of the raw approximations of a multiple root cancel in their
+            // centroid, while Newton steps drag them all to the same side
+            let value = centroid(&cluster.iter().map(|z| z.0).collect::<Vec<_>>());
+            if cluster.len() > 1 && is_multiple_root(&monic, value, &polished(&cluster)) {
+                roots.push(PolynomialRoot {
+                    value,
+                    multiplicity: cluster.len(),
+                });
+            } else {
+                roots.extend(cluster.into_iter().map(|(_, value)| PolynomialRoot {
+                    value,
+                    multiplicity: 1,
+                }));
+            }
+        }
+
+        roots
+    }
+}
+
+/// `(p(z), p'(z))` of the real polynomial with ascending coefficients `coes`
+fn eval_complex<T>(coes: &[T], z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + Copy,
+{
+    let mut y = Complex::from_real(coes[coes.len() - 1]);
+    let mut dy = Complex::from_real(T::ZERO);
+    for c in coes.iter().rev().skip(1) {
+        dy = dy * z + y;
+        y = y * z + Complex::from_real(*c);
+    }
+    (y, dy)
+}
+
+fn polished<T: Copy>(cluster: &[(Complex<T>, Complex<T>)]) -> Vec<Complex<T>> {
+    cluster.iter().map(|z| z.1).collect()
+}
+
+/// `p^(k)(z) / k!` of the real polynomial with ascending coefficients `coes`, by `k + 1`
+/// synthetic divisions by `x - z`
+fn taylor_coefficient<T>(coes: &[T], z: Complex<T>, k: usize) -> Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + FloatConst + Copy,
+{
+    let mut b: Vec<Complex<T>> = coes.iter().map(|c| Complex::from_real(*c)).collect();
+    for _ in 0..k {
+        if b.len() <= 1 {
+            return Complex::from_real(T::ZERO);
+        }
+        // b[0] becomes the remainder, b[1..] the quotient
+        for i in (0..b.len() - 1).rev() {
+            b[i] = b[i] + z * b[i + 1];
+        }
+        b.remove(0);
+    }
+    let mut remainder = Complex::from_real(T::ZERO);
+    for c in b.iter().rev() {
+        remainder = remainder * z + *c;
+    }
+    remainder
+}
+
+/// Whether the spread of `points` around `c` can be put down to rounding errors around a
+/// root of multiplicity `points.len()`
+fn is_multiple_root<T>(coes: &[T], c: Complex<T>, points: &[Complex<T>]) -> bool
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Copy,
+{
+    let m = points.len();
+    let radius = points.iter().fold(T::ZERO, |r, z| {
+        if (*z - c).norm() > r {
+            (*z - c).norm()
+        } else {
+            r
+        }
+    });
+
+    let (mut spread, mut power, mut modulus_power) = (T::ONE, T::ONE, T::ONE);
+    for _ in 0..m {
+        spread = spread * radius;
+        power = power * fl!(8.0);
+    }
+    let mut scale = T::ZERO;
+    for a in coes {
+        scale = scale + a.abs() * modulus_power;
+        modulus_power = modulus_power * c.norm();
+    }
+    let degree = T::from_f64((coes.len() - 1) as f64);
+    spread * taylor_coefficient(coes, c, m).norm() <= power * degree * T::EPSILON * scale
+}
+
+fn centroid<T>(points: &[Complex<T>]) -> Complex<T>
+where
+    T: Add<Output = T> + Div<Output = T> + FloatConst + FromF64 + Copy,
+{
+    let n = T::from_f64(points.len() as f64);
+    let mut sum = Complex::from_real(T::ZERO);
+    for p in points {
+        sum = sum + *p;
+    }
+    Complex::new(sum.re / n, sum.im / n)
+}
+
+/// Simultaneous approximation of all roots of the monic polynomial `monic`
+fn aberth<T>(monic: &[T]) -> Vec<Complex<T>>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Trig
+        + Finite
+        + Copy,
+{
+    let n = monic.len() - 1;
+
+    // start points are spread on a circle of half the Cauchy bound of the root moduli,
+    // rotated a bit to avoid the symmetry of real coefficients
+    let mut bound = T::ZERO;
+    for c in monic.iter().take(n) {
+        bound = bound.max(c.abs());
+    }
+    let radius = (bound + T::ONE) / fl!(2.0);
+
+    let mut z: Vec<Complex<T>> = (0..n)
+        .map(|k| {
+            let theta = T::PI * fl!(2.0) * T::from_f64(k as f64) / T::from_f64(n as f64) + fl!(0.4);
+            Complex::from_polar(radius, theta)
+        })
+        .collect();
+
+    for _ in 0..ABERTH_ITER_LIMIT {
+        let mut converged = true;
+        for k in 0..n {
+            let (y, dy) = eval_complex(monic, z[k]);
+            if y == Complex::from_real(T::ZERO) {
+                continue;
+            }
+            let ratio = y / dy;
+            let mut repulsion = Complex::from_real(T::ZERO);
+            for j in 0..n {
+                if j != k {
+                    repulsion = repulsion + Complex::from_real(T::ONE) / (z[k] - z[j]);
+                }
+            }
+            let offset = ratio / (Complex::from_real(T::ONE) - ratio * repulsion);
+            if offset.re.is_finite() && offset.im.is_finite() {
+                z[k] = z[k] - offset;
+            }
+            if offset.norm() > T::EPSILON * fl!(4.0) * z[k].norm().max(T::ONE) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+
+    z
+}
+
+fn newton_polish<T>(coes: &[T], mut z: Complex<T>) -> Complex<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + Abs
+        + Sqrt
+        + Copy,
+{
+    let (mut y, _) = eval_complex(coes, z);
+    for _ in 0..POLISH_ITER_LIMIT {
+        let dy = eval_complex(coes, z).1;
+        if dy == Complex::from_real(T::ZERO) {
+            break;
+        }
+        let next = z - y / dy;
+        let (next_y, _) = eval_complex(coes, next);
+        if next_y.norm() >= y.norm() {
+            break;
+        }
+        (z, y) = (next, next_y);
+    }
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    fn sorted(roots: Vec<PolynomialRoot<f64>>) -> Vec<PolynomialRoot<f64>> {
+        let mut roots = roots;
+        roots.sort_by(|r0, r1| {
+            (r0.value().re, r0.value().im)
+                .partial_cmp(&(r1.value().re, r1.value().im))
+                .unwrap()
+        });
+        roots
+    }
+
+    #[test]
+    fn test_real_roots() {
+        // x^3 - 6x^2 + 11x - 6 = (x - 1)(x - 2)(x - 3)
+        let p = Polynomial::new(1.0).with_coefficients(&[-6.0, 11.0, -6.0]);
+        let roots = sorted(p.roots());
+        assert!(roots.len() == 3);
+        for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert!(root.multiplicity() == 1);
+            assert!(root.value().re.abs_diff_eq(&expected, 1e-14));
+            assert!(root.value().im.abs_diff_eq(&0.0, 1e-14));
+        }
+    }
+
+    #[test]
+    fn test_complex_roots() {
+        // 2x^2 + 2
+        let p = Polynomial::new(2.0).with_coefficients(&[2.0, 0.0]);
+        let roots = sorted(p.roots());
+        assert!(roots.len() == 2);
+        assert!((roots[0].value() - Complex::new(0.0, -1.0))
+            .norm()
+            .abs_diff_eq(&0.0, 1e-14));
+        assert!((roots[1].value() - Complex::new(0.0, 1.0))
+            .norm()
+            .abs_diff_eq(&0.0, 1e-14));
+
+        assert!(Polynomial::new(3.0).roots().is_empty());
+        assert!(Polynomial::new(0.0)
+            .with_coefficients(&[0.0])
+            .roots()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_close_distinct_roots() {
+        // (x - 1)(x - 1.0001), well conditioned, must not be taken for a double root
+        let p = Polynomial::from_monomial_coefficients(&[1.0001, -2.0001, 1.0]);
+        let roots = sorted(p.roots());
+        assert!(roots.len() == 2);
+        for (root, expected) in roots.iter().zip([1.0, 1.0001]) {
+            println!("root = {}", root.value());
+            assert!(root.multiplicity() == 1);
+            // the coefficients are rounded, which moves the roots by about ε / 1e-4
+            assert!(root.value().re.abs_diff_eq(&expected, 1e-10));
+            assert!(root.value().im.abs_diff_eq(&0.0, 1e-10));
+        }
+
+        // and a close pair next to a true double root
+        let p = Polynomial::new(1.0).with_base_points(&[2.0, 2.0, 0.5, 0.5005]);
+        let roots = sorted(p.roots());
+        let found: Vec<(f64, usize)> = roots
+            .iter()
+            .map(|r| (r.value().re, r.multiplicity()))
+            .collect();
+        println!("roots = {found:?}");
+        assert!(roots.len() == 3);
+        for ((re, multiplicity), (expected, expected_multiplicity)) in
+            found.into_iter().zip([(0.5, 1), (0.5005, 1), (2.0, 2)])
+        {
+            assert!(multiplicity == expected_multiplicity);
+            assert!(re.abs_diff_eq(&expected, 1e-8));
+        }
+    }
+
+    #[test]
+    fn test_multiple_roots_in_newton_form() {
+        // x^2 (x - 1)^2 (x + 2)
+        let p = Polynomial::new(1.0).with_base_points(&[1.0, 0.0, -2.0, 1.0, 0.0]);
+        let roots = sorted(p.roots());
+        for root in roots.iter() {
+            println!(
+                "root = {}, multiplicity = {}",
+                root.value(),
+                root.multiplicity()
+            );
+        }
+        assert!(roots.len() == 3);
+        for (root, (expected, multiplicity)) in roots.iter().zip([(-2.0, 1), (0.0, 2), (1.0, 2)]) {
+            assert!(root.multiplicity() == multiplicity);
+            assert!(root.value().re.abs_diff_eq(&expected, 1e-8));
+            assert!(root.value().im.abs_diff_eq(&0.0, 1e-8));
+        }
+
+        let p = Polynomial::new(-0.5)
+            .with_coefficients(&[1.0, 0.5, 0.5, 2.0])
+            .with_base_points(&[0.0, 2.0, 3.0, -1.0]);
+        let roots = p.roots();
+        assert!(roots.iter().map(|r| r.multiplicity()).sum::<usize>() == 4);
+        for root in roots {
+            let (y, _) = eval_complex(&p.monomial_coefficients(), root.value());
+            assert!(
+                y.norm().abs_diff_eq(&0.0, 1e-12),
+                "p({}) = {}",
+                root.value(),
+                y
+            );
+        }
+    }
+}
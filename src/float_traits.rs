@@ -100,3 +100,87 @@ impl MaxMin for f64 {
         f64::min(self, other)
     }
 }
+
+pub trait Finite: Copy {
+    fn is_finite(self) -> bool;
+
+    fn is_nan(self) -> bool;
+}
+
+impl Finite for f32 {
+    #[inline]
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+}
+
+impl Finite for f64 {
+    #[inline]
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+}
+
+pub trait Sqrt {
+    fn sqrt(self) -> Self;
+}
+
+impl Sqrt for f32 {
+    #[inline]
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+}
+
+impl Sqrt for f64 {
+    #[inline]
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+}
+
+pub trait Trig: Sized {
+    const PI: Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+}
+
+impl Trig for f32 {
+    const PI: Self = std::f32::consts::PI;
+
+    #[inline]
+    fn sin(self) -> Self {
+        f32::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        f32::cos(self)
+    }
+}
+
+impl Trig for f64 {
+    const PI: Self = std::f64::consts::PI;
+
+    #[inline]
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+}
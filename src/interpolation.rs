@@ -0,0 +1,11 @@
+pub mod barycentric;
+pub mod chebyshev;
+pub mod chebyshev_series;
+pub mod grid2d;
+pub mod hermite;
+pub mod lagrange;
+pub mod newton;
+pub mod node_analysis;
+pub mod pchip;
+pub mod piecewise;
+pub mod spline;
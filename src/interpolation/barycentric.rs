@@ -0,0 +1,207 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::Dim1Fn,
+    float_traits::{Abs, FloatConst},
+};
+
+/// Interpolant in barycentric form
+/// `r(x) = Σ w_i y_i / (x - x_i) / Σ w_i / (x - x_i)`, evaluated in `O(n)`
+#[derive(Clone, Debug)]
+pub struct BarycentricInterpolant<T> {
+    nodes: Vec<T>,
+    values: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> BarycentricInterpolant<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + PartialOrd
+        + Abs
+        + Copy,
+{
+    /// The interpolating polynomial through `(nodes[i], values[i])`, with the weights
+    /// `w_i = 1 / ∏_{j != i} (x_i - x_j)`, built in `O(n^2)`
+    pub fn lagrange(nodes: &[T], values: &[T]) -> Self {
+        assert!(nodes.len() == values.len());
+        assert!(!nodes.is_empty(), "no interpolation node");
+
+        let weights = (0..nodes.len())
+            .map(|i| {
+                let mut prod = T::ONE;
+                for j in 0..nodes.len() {
+                    if j != i {
+                        assert!(nodes[i] != nodes[j], "duplicated interpolation node");
+                        prod = prod * (nodes[i] - nodes[j]);
+                    }
+                }
+                T::ONE / prod
+            })
+            .collect();
+
+        Self {
+            nodes: nodes.to_vec(),
+            values: values.to_vec(),
+            weights,
+        }
+    }
+
+    /// Floater–Hormann rational interpolant of blending degree `d`, which blends the
+    /// interpolating polynomials of degree `d` through consecutive nodes. It has no real
+    /// poles and converges like `h^(d+1)`, also on equispaced nodes. `nodes` must be
+    /// strictly increasing, `d = nodes.len() - 1` gives the polynomial interpolant.
+    pub fn floater_hormann(nodes: &[T], values: &[T], d: usize) -> Self {
+        assert!(nodes.len() == values.len());
+        assert!(!nodes.is_empty(), "no interpolation node");
+        assert!(
+            nodes.windows(2).all(|w| w[0] < w[1]),
+            "nodes must be strictly increasing"
+        );
+        let n = nodes.len() - 1;
+        assert!(d <= n, "blending degree larger than the number of nodes");
+
+        // w_k = (-1)^(k - d) Σ_{i ∈ J_k} ∏_{j = i, j != k}^{i + d} 1 / |x_k - x_j|,
+        // J_k = {i : 0 <= i <= n - d, k - d <= i <= k}
+        let weights = (0..=n)
+            .map(|k| {
+                let mut sum = T::ZERO;
+                for i in k.saturating_sub(d)..=k.min(n - d) {
+                    let mut prod = T::ONE;
+                    for j in i..=(i + d) {
+                        if j != k {
+                            prod = prod / (nodes[k] - nodes[j]).abs();
+                        }
+                    }
+                    sum = sum + prod;
+                }
+                if (k + d).is_multiple_of(2) {
+                    sum
+                } else {
+                    -sum
+                }
+            })
+            .collect();
+
+        Self {
+            nodes: nodes.to_vec(),
+            values: values.to_vec(),
+            weights,
+        }
+    }
+
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+}
+
+impl<T> Dim1Fn<T> for BarycentricInterpolant<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialEq
+        + Copy,
+{
+    fn eval(&self, x: T) -> T {
+        let mut num = T::ZERO;
+        let mut den = T::ZERO;
+        for i in 0..self.nodes.len() {
+            let dx = x - self.nodes[i];
+            if dx == T::ZERO {
+                return self.values[i];
+            }
+            let c = self.weights[i] / dx;
+            num = num + c * self.values[i];
+            den = den + c;
+        }
+        num / den
+    }
+}
+
+/// Both the polynomial and the Floater–Hormann interpolants are continuous on the real line
+impl<T> ContinuousFn for BarycentricInterpolant<T> where BarycentricInterpolant<T>: Dim1Fn<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{
+        dim1_equation_solve::{
+            brent::brent_solve,
+            find_root::{FindRootProblem, IterStopCondition},
+        },
+        interpolation::{chebyshev::chebyshev_nodes, newton::newton_interpolate},
+    };
+
+    fn runge(x: f64) -> f64 {
+        1.0 / (1.0 + 25.0 * x * x)
+    }
+
+    fn max_error(interpolant: &BarycentricInterpolant<f64>) -> f64 {
+        (0..=1000)
+            .map(|i| {
+                let x = -1.0 + 2.0 * i as f64 / 1000.0;
+                (interpolant.eval(x) - runge(x)).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_lagrange() {
+        let xs: Vec<f64> = chebyshev_nodes(12, -1.0..1.0);
+        let ys: Vec<f64> = xs.iter().map(|x| x.exp()).collect();
+        let bary = BarycentricInterpolant::lagrange(&xs, &ys);
+        let newton = newton_interpolate(&xs, &ys);
+        for i in 0..=20 {
+            let x = -1.0 + i as f64 / 10.0;
+            assert!(bary.eval(x).abs_diff_eq(&newton.eval(x), 1e-13));
+        }
+        assert!(bary.eval(xs[3]) == ys[3]);
+    }
+
+    #[test]
+    fn test_floater_hormann_on_equispaced_runge() {
+        let xs: Vec<f64> = (0..=20).map(|i| -1.0 + i as f64 / 10.0).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| runge(*x)).collect();
+
+        let lagrange = max_error(&BarycentricInterpolant::lagrange(&xs, &ys));
+        let fh = max_error(&BarycentricInterpolant::floater_hormann(&xs, &ys, 3));
+        println!("max error: lagrange = {lagrange:e}, floater hormann = {fh:e}");
+        assert!(lagrange > 1.0);
+        assert!(fh < 1e-2);
+
+        // d = n is the polynomial interpolant, with proportional weights
+        let full = BarycentricInterpolant::floater_hormann(&xs, &ys, 20);
+        assert!(max_error(&full).abs_diff_eq(&lagrange, 1e-6 * lagrange));
+    }
+
+    #[test]
+    fn test_inverse_interpolation() {
+        // tabulated x -> exp(x) - 2, find where it vanishes from the table only
+        let xs: Vec<f64> = (0..=10).map(|i| i as f64 * 0.1).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.exp() - 2.0).collect();
+        let fh = BarycentricInterpolant::floater_hormann(&xs, &ys, 4);
+        let problem = FindRootProblem::new(fh, 0.0..1.0);
+        let result = brent_solve(&problem, &IterStopCondition::new());
+        assert!(
+            result.root().abs_diff_eq(&2_f64.ln(), 1e-7),
+            "root = {}",
+            result.root()
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::ops::{Add, Div, Mul, Range, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::{polynomial::Polynomial, Dim1Fn},
+    float_traits::{fl, FloatConst, FromF64, Trig},
+};
+
+use super::newton::newton_interpolate;
+
+/// `n` Chebyshev nodes on `[a, b]`, `x_i = (a + b) / 2 + (b - a) / 2 * cos((2i - 1)π / 2n)`,
+/// in decreasing order
+pub fn chebyshev_nodes<T>(n: usize, range: Range<T>) -> Vec<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FromF64
+        + Trig
+        + Copy,
+{
+    let (a, b) = (range.start, range.end);
+    let center = (a + b) / fl!(2.0);
+    let half_width = (b - a) / fl!(2.0);
+    (1..=n)
+        .map(|i| {
+            let theta = T::PI * T::from_f64((2 * i - 1) as f64) / T::from_f64((2 * n) as f64);
+            center + half_width * theta.cos()
+        })
+        .collect()
+}
+
+/// Polynomial interpolating a function at the Chebyshev nodes of an interval
+#[derive(Clone, Debug)]
+pub struct ChebyshevInterpolant<T> {
+    polynomial: Polynomial<T>,
+    nodes: Vec<T>,
+    range: Range<T>,
+}
+
+impl<T> ChebyshevInterpolant<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + Trig
+        + PartialEq
+        + Copy,
+{
+    /// Samples `func` at `n` Chebyshev nodes of `range`, the interpolant has degree `n - 1`
+    pub fn new<F: Dim1Fn<T>>(func: &F, range: Range<T>, n: usize) -> Self {
+        assert!(n > 0);
+        let nodes = chebyshev_nodes(n, range.clone());
+        let values: Vec<T> = nodes.iter().map(|x| func.eval(*x)).collect();
+        Self {
+            polynomial: newton_interpolate(&nodes, &values),
+            nodes,
+            range,
+        }
+    }
+
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    pub fn range(&self) -> Range<T> {
+        self.range.clone()
+    }
+
+    /// Theoretical bound of `|f(x) - P(x)|` on the interval,
+    /// `max|f^(n)| / n! * ((b - a) / 2)^n / 2^(n - 1)`, where `max_nth_derivative`
+    /// bounds `|f^(n)|` on the interval and `n` is the number of nodes
+    pub fn error_bound(&self, max_nth_derivative: T) -> T {
+        let half_width = (self.range.end - self.range.start) / fl!(2.0);
+        let mut bound = max_nth_derivative * fl!(2.0);
+        for i in 1..=self.nodes.len() {
+            bound = bound * half_width / (T::from_f64(i as f64) * fl!(2.0));
+        }
+        bound
+    }
+}
+
+impl<T> Dim1Fn<T> for ChebyshevInterpolant<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    fn eval(&self, x: T) -> T {
+        self.polynomial.nest_mul(x)
+    }
+}
+
+impl<T> ContinuousFn for ChebyshevInterpolant<T> where ChebyshevInterpolant<T>: Dim1Fn<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    struct Sin;
+
+    impl Dim1Fn<f64> for Sin {
+        fn eval(&self, x: f64) -> f64 {
+            x.sin()
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_nodes() {
+        let nodes = chebyshev_nodes(4, -1.0..1.0);
+        let p = Polynomial::new(8.0).with_base_points(&nodes);
+        // T_4(x) = 8x^4 - 8x^2 + 1
+        assert!(p.approx_eq(
+            &Polynomial::new(8.0).with_coefficients(&[1.0, 0.0, -8.0, 0.0]),
+            1e-14
+        ));
+
+        let nodes = chebyshev_nodes(3, 2.0..6.0);
+        assert!(nodes[1].abs_diff_eq(&4.0, 1e-15));
+        assert!(nodes[0].abs_diff_eq(&(4.0 + 3_f64.sqrt()), 1e-14));
+    }
+
+    #[test]
+    fn test_error_bound() {
+        let range = 0.0..std::f64::consts::FRAC_PI_2;
+        let interpolant = ChebyshevInterpolant::new(&Sin, range.clone(), 10);
+        // all derivatives of sin are bounded by 1
+        let bound = interpolant.error_bound(1.0);
+        println!("error bound = {bound:e}");
+
+        let mut max_err: f64 = 0.0;
+        for i in 0..=1000 {
+            let x = range.end * i as f64 / 1000.0;
+            max_err = max_err.max((interpolant.eval(x) - x.sin()).abs());
+        }
+        println!("max error = {max_err:e}");
+        assert!(max_err <= bound);
+        assert!(bound < 1e-10);
+    }
+}
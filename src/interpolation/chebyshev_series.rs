@@ -0,0 +1,423 @@
+use std::ops::{Add, Div, Mul, Neg, Range, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::{polynomial::Polynomial, Dim1Fn},
+    float_traits::{fl, Abs, FloatConst, FromF64, MaxMin, Sqrt, Trig},
+    matrix::{FullMatrix, MatrixMajor},
+};
+
+/// Largest degree tried by [`ChebyshevSeries::new`]
+const MAX_DEGREE: usize = 4096;
+
+/// Truncated Chebyshev expansion `f(x) = Σ c_k T_k(t)` on `[a, b]`, where
+/// `t = (2x - a - b) / (b - a)` maps the interval onto `[-1, 1]`
+#[derive(Clone, Debug)]
+pub struct ChebyshevSeries<T> {
+    coefficients: Vec<T>,
+    range: Range<T>,
+}
+
+impl<T> ChebyshevSeries<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Trig
+        + Copy,
+{
+    pub fn from_coefficients(coefficients: &[T], range: Range<T>) -> Self {
+        assert!(!coefficients.is_empty());
+        assert!(range.start < range.end);
+        Self {
+            coefficients: coefficients.to_vec(),
+            range,
+        }
+    }
+
+    /// Approximates `func` on `range`, doubling the degree from 16 until the last
+    /// coefficients fall below `tolorency` relative to the largest one, then chopping the
+    /// negligible tail. Tolorencies close to `EPSILON` may not be reached because of
+    /// rounding, the expansion of degree 4096 is returned if it never converges.
+    pub fn new<F: Dim1Fn<T>>(func: &F, range: Range<T>, tolorency: T) -> Self {
+        let mut degree = 16;
+        loop {
+            let mut series = Self::with_degree(func, range.clone(), degree);
+            let scale = series
+                .coefficients
+                .iter()
+                .fold(T::ZERO, |m, c| m.max(c.abs()));
+            let threshold = tolorency * scale;
+            let converged = series.coefficients[degree - 2..]
+                .iter()
+                .all(|c| c.abs() <= threshold);
+            if converged || degree >= MAX_DEGREE {
+                series.chop(threshold);
+                return series;
+            }
+            degree *= 2;
+        }
+    }
+
+    /// The expansion of the given degree interpolating `func` at the Chebyshev extreme
+    /// points `cos(πj / degree)` mapped onto `range`
+    pub fn with_degree<F: Dim1Fn<T>>(func: &F, range: Range<T>, degree: usize) -> Self {
+        assert!(range.start < range.end);
+        if degree == 0 {
+            let center = (range.start + range.end) / fl!(2.0);
+            return Self::from_coefficients(&[func.eval(center)], range);
+        }
+
+        let n = degree;
+        // cos(πm / n) for m in 0..2n
+        let cos: Vec<T> = (0..2 * n)
+            .map(|m| (T::PI * T::from_f64(m as f64) / T::from_f64(n as f64)).cos())
+            .collect();
+        let (center, half_width) = (
+            (range.start + range.end) / fl!(2.0),
+            (range.end - range.start) / fl!(2.0),
+        );
+        let values: Vec<T> = (0..=n)
+            .map(|j| func.eval(center + half_width * cos[j]))
+            .collect();
+
+        // discrete cosine transform, c_k = 2 / n Σ'' f_j cos(πjk / n) where the end terms
+        // are halved
+        let coefficients = (0..=n)
+            .map(|k| {
+                let mut sum = (values[0] + values[n] * cos[(n * k) % (2 * n)]) / fl!(2.0);
+                for j in 1..n {
+                    sum = sum + values[j] * cos[(j * k) % (2 * n)];
+                }
+                let c = sum * fl!(2.0) / T::from_f64(n as f64);
+                if k == 0 || k == n {
+                    c / fl!(2.0)
+                } else {
+                    c
+                }
+            })
+            .collect();
+
+        Self {
+            coefficients,
+            range,
+        }
+    }
+
+    /// Exact expansion of a polynomial on `range`
+    pub fn from_polynomial(p: &Polynomial<T>, range: Range<T>) -> Self {
+        let mut series = Self::with_degree(p, range, p.degree());
+        series.chop(T::ZERO);
+        series
+    }
+
+    /// Removes the trailing coefficients not larger than `threshold` in absolute value,
+    /// keeping at least one
+    fn chop(&mut self, threshold: T) {
+        while self.coefficients.len() > 1
+            && self.coefficients[self.coefficients.len() - 1].abs() <= threshold
+        {
+            self.coefficients.pop();
+        }
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    pub fn range(&self) -> Range<T> {
+        self.range.clone()
+    }
+
+    fn unit_point(&self, x: T) -> T {
+        (x * fl!(2.0) - self.range.start - self.range.end) / (self.range.end - self.range.start)
+    }
+
+    fn range_point(&self, t: T) -> T {
+        (self.range.start + self.range.end + (self.range.end - self.range.start) * t) / fl!(2.0)
+    }
+
+    /// The derivative as a series of one degree less, from the recurrence
+    /// `c'_{k-1} = c'_{k+1} + 2k c_k`
+    pub fn derivative(&self) -> Self {
+        let n = self.degree();
+        if n == 0 {
+            return Self::from_coefficients(&[T::ZERO], self.range());
+        }
+        let scale = T::from_f64(2.0) / (self.range.end - self.range.start);
+        let mut d = vec![T::ZERO; n + 2];
+        for k in (1..=n).rev() {
+            d[k - 1] = d[k + 1] + self.coefficients[k] * T::from_f64((2 * k) as f64);
+        }
+        d[0] = d[0] / fl!(2.0);
+        d.truncate(n);
+        let d: Vec<T> = d.into_iter().map(|c| c * scale).collect();
+        Self::from_coefficients(&d, self.range())
+    }
+
+    /// The antiderivative vanishing at the start of the range, as a series of one degree
+    /// more, with `C_k = (c_{k-1} - c_{k+1}) / 2k`
+    pub fn antiderivative(&self) -> Self {
+        let n = self.degree();
+        let half_width = (self.range.end - self.range.start) / fl!(2.0);
+        let c = |k: usize| {
+            if k <= n {
+                self.coefficients[k]
+            } else {
+                T::ZERO
+            }
+        };
+
+        let mut integral: Vec<T> = (0..=n + 1)
+            .map(|k| match k {
+                0 => T::ZERO,
+                1 => (c(0) * fl!(2.0) - c(2)) / fl!(2.0) * half_width,
+                _ => (c(k - 1) - c(k + 1)) / T::from_f64((2 * k) as f64) * half_width,
+            })
+            .collect();
+        // T_k(-1) = (-1)^k
+        let mut at_start = T::ZERO;
+        for (k, ck) in integral.iter().enumerate().skip(1) {
+            at_start = if k % 2 == 0 {
+                at_start + *ck
+            } else {
+                at_start - *ck
+            };
+        }
+        integral[0] = -at_start;
+        Self::from_coefficients(&integral, self.range())
+    }
+
+    /// Definite integral over the range, `Σ_{k even} 2 c_k / (1 - k^2)` scaled to `[a, b]`
+    pub fn integrate(&self) -> T {
+        let half_width = (self.range.end - self.range.start) / fl!(2.0);
+        let mut sum = T::ZERO;
+        for (k, c) in self.coefficients.iter().enumerate().step_by(2) {
+            sum = sum + *c * fl!(2.0) / T::from_f64(1.0 - (k * k) as f64);
+        }
+        sum * half_width
+    }
+
+    /// The real roots in the range, sorted, computed as the eigenvalues of the colleague
+    /// matrix of the series. Eigenvalues within `sqrt(EPSILON)` of the real segment
+    /// `[-1, 1]` are kept and polished by Newton steps.
+    ///
+    /// A series vanishing identically has no isolated roots, an empty list is returned.
+    pub fn roots(&self) -> Vec<T> {
+        let scale = self
+            .coefficients
+            .iter()
+            .fold(T::ZERO, |m, c| m.max(c.abs()));
+        let mut c = self.coefficients.clone();
+        while c.len() > 1 && c[c.len() - 1].abs() <= T::EPSILON * scale {
+            c.pop();
+        }
+        let n = c.len() - 1;
+        if n == 0 {
+            return Vec::default();
+        }
+
+        let candidates: Vec<T> = if n == 1 {
+            vec![-c[0] / c[1]]
+        } else {
+            // T_1 = t T_0, t T_k = (T_{k-1} + T_{k+1}) / 2, and T_n from the series
+            let mut colleague = FullMatrix::new(MatrixMajor::Row, n, vec![T::ZERO; n * n]);
+            colleague.set(0, 1, T::ONE);
+            for i in 1..n {
+                colleague.set(i, i - 1, fl!(0.5));
+                if i + 1 < n {
+                    colleague.set(i, i + 1, fl!(0.5));
+                }
+            }
+            for (k, ck) in c.iter().take(n).enumerate() {
+                let old = colleague.get(n - 1, k);
+                colleague.set(n - 1, k, old - *ck / (c[n] * fl!(2.0)));
+            }
+            match colleague.eigenvalues() {
+                Some(eigenvalues) => {
+                    let tol = T::EPSILON.sqrt();
+                    eigenvalues
+                        .into_iter()
+                        .filter(|z| z.im.abs() <= tol && z.re.abs() <= T::ONE + tol)
+                        .map(|z| z.re)
+                        .collect()
+                }
+                None => Vec::default(),
+            }
+        };
+
+        let derivative = self.derivative();
+        let mut roots: Vec<T> = candidates
+            .into_iter()
+            .map(|t| {
+                let mut x = self.range_point(t.max(-T::ONE).min(T::ONE));
+                for _ in 0..3 {
+                    let dy = derivative.eval(x);
+                    if dy == T::ZERO {
+                        break;
+                    }
+                    let next = x - self.eval(x) / dy;
+                    if !(next >= self.range.start && next <= self.range.end) {
+                        break;
+                    }
+                    x = next;
+                }
+                x
+            })
+            .collect();
+        roots.sort_by(|x0, x1| x0.partial_cmp(x1).unwrap_or(std::cmp::Ordering::Equal));
+        roots
+    }
+
+    /// The series as a polynomial in `x` in monomial form
+    pub fn to_polynomial(&self) -> Polynomial<T> {
+        let (a, b) = (self.range.start, self.range.end);
+        // t as a polynomial in x
+        let t =
+            Polynomial::new(T::from_f64(2.0) / (b - a)).with_coefficients(&[-(a + b) / (b - a)]);
+        let two_t = t.scale(fl!(2.0));
+
+        // Clenshaw recurrence on polynomials
+        let mut b1 = Polynomial::new(T::ZERO);
+        let mut b2 = Polynomial::new(T::ZERO);
+        for c in self.coefficients.iter().skip(1).rev() {
+            let b0 = &(&(&two_t * &b1) - &b2) + &Polynomial::new(*c);
+            b2 = b1;
+            b1 = b0;
+        }
+        &(&(&t * &b1) - &b2) + &Polynomial::new(self.coefficients[0])
+    }
+}
+
+impl<T> Dim1Fn<T> for ChebyshevSeries<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Trig
+        + Copy,
+{
+    /// Clenshaw recurrence `b_k = c_k + 2t b_{k+1} - b_{k+2}`, `f = c_0 + t b_1 - b_2`
+    fn eval(&self, x: T) -> T {
+        let t = self.unit_point(x);
+        let (mut b1, mut b2) = (T::ZERO, T::ZERO);
+        for c in self.coefficients.iter().skip(1).rev() {
+            let b0 = *c + t * b1 * fl!(2.0) - b2;
+            b2 = b1;
+            b1 = b0;
+        }
+        self.coefficients[0] + t * b1 - b2
+    }
+}
+
+impl<T> ContinuousFn for ChebyshevSeries<T> where ChebyshevSeries<T>: Dim1Fn<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    struct Exp;
+
+    impl Dim1Fn<f64> for Exp {
+        fn eval(&self, x: f64) -> f64 {
+            x.exp()
+        }
+    }
+
+    struct Sin;
+
+    impl Dim1Fn<f64> for Sin {
+        fn eval(&self, x: f64) -> f64 {
+            x.sin()
+        }
+    }
+
+    #[test]
+    fn test_adaptive_degree() {
+        let series = ChebyshevSeries::new(&Exp, 0.0..2.0, 1e-14);
+        println!("degree = {}", series.degree());
+        assert!(series.degree() < 20);
+        for i in 0..=100 {
+            let x = i as f64 * 0.02;
+            assert!(series.eval(x).abs_diff_eq(&x.exp(), 1e-13));
+        }
+
+        let series = ChebyshevSeries::new(&Sin, -10.0..10.0, 1e-14);
+        println!("degree = {}", series.degree());
+        assert!(series.eval(1.0).abs_diff_eq(&1_f64.sin(), 1e-13));
+    }
+
+    #[test]
+    fn test_calculus() {
+        let series = ChebyshevSeries::new(&Exp, 0.0..2.0, 1e-14);
+        let (derivative, antiderivative) = (series.derivative(), series.antiderivative());
+        for i in 0..=20 {
+            let x = i as f64 * 0.1;
+            assert!(derivative.eval(x).abs_diff_eq(&x.exp(), 1e-11));
+            assert!(antiderivative.eval(x).abs_diff_eq(&(x.exp() - 1.0), 1e-13));
+        }
+        let expected = 2_f64.exp() - 1.0;
+        assert!(series.integrate().abs_diff_eq(&expected, 1e-13));
+        assert!(antiderivative.eval(2.0).abs_diff_eq(&expected, 1e-13));
+    }
+
+    #[test]
+    fn test_roots() {
+        let series = ChebyshevSeries::new(&Sin, -10.0..10.0, 1e-14);
+        let roots = series.roots();
+        println!("roots = {roots:?}");
+        assert!(roots.len() == 7);
+        for (k, root) in (-3..=3).zip(roots) {
+            assert!(root.abs_diff_eq(&(k as f64 * std::f64::consts::PI), 1e-12));
+        }
+
+        // roots outside the range are dropped
+        let p = Polynomial::new(1.0).with_base_points(&[-0.5, 0.25, 3.0]);
+        let roots = ChebyshevSeries::from_polynomial(&p, -1.0..1.0).roots();
+        assert!(roots.len() == 2);
+        assert!(roots[0].abs_diff_eq(&-0.5, 1e-14) && roots[1].abs_diff_eq(&0.25, 1e-14));
+    }
+
+    #[test]
+    fn test_polynomial_conversion() {
+        let p = Polynomial::new(2.0)
+            .with_coefficients(&[1.0, -1.0, 0.5])
+            .with_base_points(&[1.0, 2.0, -0.5]);
+        let series = ChebyshevSeries::from_polynomial(&p, -1.0..3.0);
+        assert!(series.degree() == 3);
+        for i in 0..=8 {
+            let x = -1.0 + i as f64 * 0.5;
+            assert!(series.eval(x).abs_diff_eq(&p.eval(x), 1e-13));
+        }
+        assert!(series.to_polynomial().approx_eq(&p, 1e-13));
+
+        // T_3 on [-1, 1]
+        let t3 = ChebyshevSeries::from_coefficients(&[0.0, 0.0, 0.0, 1.0], -1.0..1.0);
+        assert!(t3.to_polynomial().approx_eq(
+            &Polynomial::new(4.0).with_coefficients(&[0.0, -3.0, 0.0]),
+            1e-15
+        ));
+    }
+}
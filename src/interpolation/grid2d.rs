@@ -0,0 +1,401 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    float_traits::{FloatConst, FromF64},
+    matrix::FullMatrix,
+};
+
+use super::{
+    piecewise::hermite_cubic,
+    spline::{CubicSpline, SplineEndCondition},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridInterpolationMode<T> {
+    /// Linear in each direction on every cell, continuous but with jumping gradient
+    Bilinear,
+    /// Bicubic Hermite patches, with the partial derivatives at the grid points estimated
+    /// by the parabola through three neighbouring points. The gradient is continuous.
+    Bicubic,
+    /// Tensor product of cubic splines with the given end condition in both directions,
+    /// the clamped condition imposes the same end slopes on every grid line. On each cell
+    /// it is the bicubic Hermite patch of its partial derivatives at the grid points.
+    Spline(SplineEndCondition<T>),
+}
+
+/// Value of the interpolant at points outside the grid
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Extrapolation<T> {
+    /// The value at the nearest point of the grid rectangle, with zero derivative across
+    /// the boundary
+    Nearest,
+    /// The patches of the boundary cells are continued
+    Extend,
+    /// A constant, with zero gradient
+    Fill(T),
+}
+
+/// Interpolation over the rectilinear grid `xs × ys`, the value at `(xs[i], ys[j])` is
+/// entry `(i, j)` of the value matrix
+#[derive(Clone, Debug)]
+pub struct GridInterpolator<T> {
+    xs: Vec<T>,
+    ys: Vec<T>,
+    /// `values[i][j]` at `(xs[i], ys[j])`
+    values: Vec<Vec<T>>,
+    mode: GridInterpolationMode<T>,
+    extrapolation: Extrapolation<T>,
+    /// `∂f/∂x`, `∂f/∂y` and `∂²f/∂x∂y` at the grid points, for the bicubic and the
+    /// spline modes
+    derivatives: Option<[Vec<Vec<T>>; 3]>,
+}
+
+impl<T> GridInterpolator<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    /// `xs` and `ys` must be strictly increasing with at least 2 points each, and `values`
+    /// must have `xs.len()` rows and `ys.len()` columns. Points out of the grid are
+    /// extrapolated by [`Extrapolation::Extend`] unless set otherwise.
+    pub fn new(xs: &[T], ys: &[T], values: &FullMatrix<T>, mode: GridInterpolationMode<T>) -> Self {
+        assert!(
+            xs.len() >= 2 && ys.len() >= 2,
+            "a grid needs 2 points per axis"
+        );
+        assert!(
+            xs.windows(2).all(|w| w[0] < w[1]) && ys.windows(2).all(|w| w[0] < w[1]),
+            "grid coordinates must be strictly increasing"
+        );
+        assert!(values.row_count() == xs.len() && values.column_count() == ys.len());
+
+        let values: Vec<Vec<T>> = (0..xs.len())
+            .map(|i| (0..ys.len()).map(|j| values.entry(i, j)).collect())
+            .collect();
+
+        let derivatives = match mode {
+            GridInterpolationMode::Bilinear => None,
+            GridInterpolationMode::Bicubic => {
+                let fy = along_rows(&values, |row| parabolic_slopes(ys, row));
+                let fx = along_columns(&values, |column| parabolic_slopes(xs, column));
+                let fxy = along_columns(&fy, |column| parabolic_slopes(xs, column));
+                Some([fx, fy, fxy])
+            }
+            GridInterpolationMode::Spline(condition) => {
+                let slopes = |knots: &[T], data: &[T], condition| {
+                    CubicSpline::new(knots, data, condition)
+                        .as_piecewise_cubic()
+                        .slopes()
+                        .to_vec()
+                };
+                // the clamped construction is affine in the data, the given end slopes
+                // belong to the values and not to their y-derivatives
+                let derivative_condition = match condition {
+                    SplineEndCondition::Clamped(_, _) => {
+                        SplineEndCondition::Clamped(T::ZERO, T::ZERO)
+                    }
+                    condition => condition,
+                };
+                let fy = along_rows(&values, |row| slopes(ys, row, condition));
+                let fx = along_columns(&values, |column| slopes(xs, column, condition));
+                let fxy = along_columns(&fy, |column| slopes(xs, column, derivative_condition));
+                Some([fx, fy, fxy])
+            }
+        };
+
+        Self {
+            xs: xs.to_vec(),
+            ys: ys.to_vec(),
+            values,
+            mode,
+            extrapolation: Extrapolation::Extend,
+            derivatives,
+        }
+    }
+
+    pub fn with_extrapolation(mut self, extrapolation: Extrapolation<T>) -> Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    pub fn xs(&self) -> &[T] {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &[T] {
+        &self.ys
+    }
+
+    pub fn mode(&self) -> GridInterpolationMode<T> {
+        self.mode
+    }
+
+    pub fn extrapolation(&self) -> Extrapolation<T> {
+        self.extrapolation
+    }
+
+    pub fn eval(&self, x: T, y: T) -> T {
+        self.eval_with_gradient(x, y).0
+    }
+
+    /// `(∂f/∂x, ∂f/∂y)`
+    pub fn gradient(&self, x: T, y: T) -> (T, T) {
+        self.eval_with_gradient(x, y).1
+    }
+
+    pub fn eval_with_gradient(&self, x: T, y: T) -> (T, (T, T)) {
+        let (x_start, x_end) = (self.xs[0], self.xs[self.xs.len() - 1]);
+        let (y_start, y_end) = (self.ys[0], self.ys[self.ys.len() - 1]);
+        let x_inside = x >= x_start && x <= x_end;
+        let y_inside = y >= y_start && y <= y_end;
+
+        match self.extrapolation {
+            _ if x_inside && y_inside => self.eval_inside(x, y),
+            Extrapolation::Extend => self.eval_inside(x, y),
+            Extrapolation::Fill(value) => (value, (T::ZERO, T::ZERO)),
+            Extrapolation::Nearest => {
+                let clamp = |v: T, start: T, end: T| {
+                    if v < start {
+                        start
+                    } else if v > end {
+                        end
+                    } else {
+                        v
+                    }
+                };
+                let (value, (dx, dy)) =
+                    self.eval_inside(clamp(x, x_start, x_end), clamp(y, y_start, y_end));
+                let dx = if x_inside { dx } else { T::ZERO };
+                let dy = if y_inside { dy } else { T::ZERO };
+                (value, (dx, dy))
+            }
+        }
+    }
+
+    /// Index of the grid cell containing `v` along `axis`, the end cells extend to infinity
+    fn cell(axis: &[T], v: T) -> usize {
+        axis.partition_point(|a| *a <= v).clamp(1, axis.len() - 1) - 1
+    }
+
+    fn eval_inside(&self, x: T, y: T) -> (T, (T, T)) {
+        match self.mode {
+            GridInterpolationMode::Bilinear => self.bilinear(x, y),
+            GridInterpolationMode::Bicubic | GridInterpolationMode::Spline(_) => self.bicubic(x, y),
+        }
+    }
+
+    fn bilinear(&self, x: T, y: T) -> (T, (T, T)) {
+        let (i, j) = (Self::cell(&self.xs, x), Self::cell(&self.ys, y));
+        let (hx, hy) = (self.xs[i + 1] - self.xs[i], self.ys[j + 1] - self.ys[j]);
+        let (t, u) = ((x - self.xs[i]) / hx, (y - self.ys[j]) / hy);
+        let f = |di: usize, dj: usize| self.values[i + di][j + dj];
+
+        let bottom = f(0, 0) + (f(1, 0) - f(0, 0)) * t;
+        let top = f(0, 1) + (f(1, 1) - f(0, 1)) * t;
+        let dx = ((f(1, 0) - f(0, 0)) * (T::ONE - u) + (f(1, 1) - f(0, 1)) * u) / hx;
+        (bottom + (top - bottom) * u, (dx, (top - bottom) / hy))
+    }
+
+    /// Hermite cubics along y on both grid lines of the cell for the value and the
+    /// x-derivative, then a Hermite cubic along x through them
+    fn bicubic(&self, x: T, y: T) -> (T, (T, T)) {
+        let [fx, fy, fxy] = self.derivatives.as_ref().unwrap();
+        let (i, j) = (Self::cell(&self.xs, x), Self::cell(&self.ys, y));
+        let (y0, y1) = (self.ys[j], self.ys[j + 1]);
+
+        let along_y = |f: &[Vec<T>], fy: &[Vec<T>], k: usize| {
+            hermite_cubic((y0, f[k][j], fy[k][j]), (y1, f[k][j + 1], fy[k][j + 1]), y)
+        };
+        // (g, ∂g/∂y) of f and of ∂f/∂x on the lines x = xs[i] and x = xs[i + 1]
+        let (g0, g1) = (
+            along_y(&self.values, fy, i),
+            along_y(&self.values, fy, i + 1),
+        );
+        let (gx0, gx1) = (along_y(fx, fxy, i), along_y(fx, fxy, i + 1));
+
+        let (x0, x1) = (self.xs[i], self.xs[i + 1]);
+        let (value, dx) = hermite_cubic((x0, g0.0, gx0.0), (x1, g1.0, gx1.0), x);
+        let (dy, _) = hermite_cubic((x0, g0.1, gx0.1), (x1, g1.1, gx1.1), x);
+        (value, (dx, dy))
+    }
+}
+
+/// `slopes` applied to every row of `m`
+fn along_rows<T>(m: &[Vec<T>], slopes: impl Fn(&[T]) -> Vec<T>) -> Vec<Vec<T>> {
+    m.iter().map(|row| slopes(row)).collect()
+}
+
+/// `slopes` applied to every column of `m`
+fn along_columns<T: Copy>(m: &[Vec<T>], slopes: impl Fn(&[T]) -> Vec<T>) -> Vec<Vec<T>> {
+    let transpose = |m: &[Vec<T>]| -> Vec<Vec<T>> {
+        (0..m[0].len())
+            .map(|j| m.iter().map(|row| row[j]).collect())
+            .collect()
+    };
+    transpose(&along_rows(&transpose(m), slopes))
+}
+
+/// Slopes at the points of the data `(xs[i], ys[i])`, from the parabola through the point
+/// and its neighbours, or the secant when there are only two points
+fn parabolic_slopes<T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + FromF64 + Copy,
+{
+    let n = xs.len();
+    let h: Vec<T> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let d: Vec<T> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+    if n == 2 {
+        return vec![d[0]; 2];
+    }
+
+    let mut slopes = Vec::with_capacity(n);
+    slopes.push(d[0] + (d[0] - d[1]) * h[0] / (h[0] + h[1]));
+    for i in 1..n - 1 {
+        slopes.push((d[i - 1] * h[i] + d[i] * h[i - 1]) / (h[i - 1] + h[i]));
+    }
+    let (p, q) = (h[n - 3], h[n - 2]);
+    slopes.push(d[n - 2] + (d[n - 2] - d[n - 3]) * q / (p + q));
+    slopes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::matrix::MatrixMajor;
+
+    const XS: [f64; 5] = [0.0, 0.5, 1.2, 2.0, 3.0];
+    const YS: [f64; 4] = [-1.0, 0.0, 0.5, 2.0];
+
+    fn grid(f: impl Fn(f64, f64) -> f64) -> FullMatrix<f64> {
+        let values = XS
+            .iter()
+            .flat_map(|x| YS.iter().map(move |y| (*x, *y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        FullMatrix::new(MatrixMajor::Row, YS.len(), values)
+    }
+
+    fn check(
+        interpolator: &GridInterpolator<f64>,
+        f: impl Fn(f64, f64) -> (f64, f64, f64),
+        tol: f64,
+    ) {
+        for i in 0..=20 {
+            for j in 0..=20 {
+                let (x, y) = (-0.5 + i as f64 * 0.2, -1.5 + j as f64 * 0.2);
+                let (value, (dx, dy)) = interpolator.eval_with_gradient(x, y);
+                let (expected, expected_dx, expected_dy) = f(x, y);
+                assert!(value.abs_diff_eq(&expected, tol), "f({x}, {y}) = {value}");
+                assert!(dx.abs_diff_eq(&expected_dx, tol));
+                assert!(dy.abs_diff_eq(&expected_dy, tol));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bilinear() {
+        // bilinear functions are reproduced, also when extended
+        let f = |x: f64, y: f64| {
+            (
+                1.0 + 2.0 * x - y + 0.5 * x * y,
+                2.0 + 0.5 * y,
+                -1.0 + 0.5 * x,
+            )
+        };
+        let values = grid(|x, y| f(x, y).0);
+        let interpolator =
+            GridInterpolator::new(&XS, &YS, &values, GridInterpolationMode::Bilinear);
+        check(&interpolator, f, 1e-13);
+    }
+
+    #[test]
+    fn test_bicubic() {
+        // the parabolic slopes are exact for quadratics, so is the bicubic patch
+        let f = |x: f64, y: f64| {
+            (
+                x * x - x * y + 2.0 * y * y + y,
+                2.0 * x - y,
+                -x + 4.0 * y + 1.0,
+            )
+        };
+        let values = grid(|x, y| f(x, y).0);
+        let interpolator = GridInterpolator::new(&XS, &YS, &values, GridInterpolationMode::Bicubic);
+        check(&interpolator, f, 1e-12);
+
+        // smooth data
+        let values = grid(|x, y| (x * y).sin());
+        let interpolator = GridInterpolator::new(&XS, &YS, &values, GridInterpolationMode::Bicubic);
+        println!("bicubic at (1, 1): {}", interpolator.eval(1.0, 1.0));
+        assert!(interpolator.eval(1.0, 1.0).abs_diff_eq(&1_f64.sin(), 0.1));
+    }
+
+    #[test]
+    fn test_tensor_spline() {
+        // not-a-knot splines reproduce cubics in each direction
+        let f = |x: f64, y: f64| {
+            (
+                x * x * x * y - 2.0 * x * y * y * y + y,
+                3.0 * x * x * y - 2.0 * y * y * y,
+                x * x * x - 6.0 * x * y * y + 1.0,
+            )
+        };
+        let values = grid(|x, y| f(x, y).0);
+        let interpolator = GridInterpolator::new(
+            &XS,
+            &YS,
+            &values,
+            GridInterpolationMode::Spline(SplineEndCondition::NotAKnot),
+        );
+        check(&interpolator, f, 1e-10);
+    }
+
+    #[test]
+    fn test_clamped_tensor_spline_gradient() {
+        // the end slopes agree with the data, which is reproduced with its gradient
+        let values = grid(|x, y| x + y);
+        let clamped = GridInterpolationMode::Spline(SplineEndCondition::Clamped(1.0, 1.0));
+        let interpolator = GridInterpolator::new(&XS, &YS, &values, clamped);
+        check(&interpolator, |x, y| (x + y, 1.0, 1.0), 1e-12);
+
+        // the y end slopes do not fit the data, the surface bends along y and its
+        // gradient is checked by central differences
+        let values = grid(|x, _| x);
+        let interpolator = GridInterpolator::new(&XS, &YS, &values, clamped);
+        let h = 1e-6;
+        for (x, y) in [(1.5, 1.5), (0.2, -0.7), (2.6, 0.1)] {
+            let (dx, dy) = interpolator.gradient(x, y);
+            let fd_dx = (interpolator.eval(x + h, y) - interpolator.eval(x - h, y)) / (2.0 * h);
+            let fd_dy = (interpolator.eval(x, y + h) - interpolator.eval(x, y - h)) / (2.0 * h);
+            println!("gradient at ({x}, {y}) = ({dx}, {dy}), differences ({fd_dx}, {fd_dy})");
+            assert!(dx.abs_diff_eq(&fd_dx, 1e-8) && dy.abs_diff_eq(&fd_dy, 1e-8));
+        }
+    }
+
+    #[test]
+    fn test_extrapolation() {
+        let values = grid(|x, y| x + 10.0 * y);
+        let interpolator =
+            GridInterpolator::new(&XS, &YS, &values, GridInterpolationMode::Bilinear);
+
+        let nearest = interpolator
+            .clone()
+            .with_extrapolation(Extrapolation::Nearest);
+        assert!(nearest.eval(4.0, 0.0).abs_diff_eq(&3.0, 1e-14));
+        assert!(nearest.eval_with_gradient(4.0, 0.0).1 == (0.0, 10.0));
+        assert!(nearest.eval(-1.0, -3.0).abs_diff_eq(&-10.0, 1e-14));
+
+        let fill = interpolator.with_extrapolation(Extrapolation::Fill(f64::NAN));
+        assert!(fill.eval(1.0, 5.0).is_nan());
+        assert!(fill.eval(1.0, 1.0).abs_diff_eq(&11.0, 1e-14));
+    }
+}
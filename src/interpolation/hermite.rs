@@ -0,0 +1,97 @@
+use std::ops::{Div, Sub};
+
+use crate::{dim1_func::polynomial::Polynomial, float_traits::FromF64};
+
+/// Hermite interpolating polynomial in Newton form, matching `data[i]`, the value and the
+/// first `data[i].len() - 1` derivatives, at `nodes[i]`.
+///
+/// The coefficients are the confluent divided differences over the nodes repeated by
+/// their multiplicity, `f[x_i, ..., x_i] = f^(k)(x_i) / k!` for `k + 1` repetitions.
+///
+/// Panics if a node is given twice or comes without data.
+pub fn hermite_interpolate<T>(nodes: &[T], data: &[Vec<T>]) -> Polynomial<T>
+where
+    T: Sub<Output = T> + Div<Output = T> + FromF64 + PartialEq + Copy,
+{
+    assert!(nodes.len() == data.len());
+    assert!(
+        data.iter().all(|d| !d.is_empty()),
+        "no interpolation data at a node"
+    );
+    for (i, x) in nodes.iter().enumerate() {
+        assert!(!nodes[..i].contains(x), "duplicated interpolation node");
+    }
+
+    // the repeated nodes, with the index of their data
+    let z: Vec<(T, usize)> = nodes
+        .iter()
+        .zip(data.iter())
+        .enumerate()
+        .flat_map(|(i, (x, d))| std::iter::repeat_n((*x, i), d.len()))
+        .collect();
+    let n = z.len();
+
+    // column k of the divided difference table, in place: table[j] = f[z_j ... z_{j+k}]
+    let mut table: Vec<T> = z.iter().map(|(_, i)| data[*i][0]).collect();
+    let mut coefficients = vec![table[0]];
+    for k in 1..n {
+        for j in 0..n - k {
+            let (zj, i) = z[j];
+            table[j] = if zj == z[j + k].0 {
+                data[i][k] / factorial(k)
+            } else {
+                (table[j + 1] - table[j]) / (z[j + k].0 - zj)
+            };
+        }
+        coefficients.push(table[0]);
+    }
+
+    let base_points: Vec<T> = z[..n - 1].iter().map(|(x, _)| *x).collect();
+    Polynomial::new(coefficients[n - 1])
+        .with_coefficients(&coefficients[..n - 1])
+        .with_base_points(&base_points)
+}
+
+fn factorial<T: FromF64>(k: usize) -> T {
+    T::from_f64((1..=k).map(|i| i as f64).product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{dim1_func::Dim1FnWithDerivative, interpolation::newton::newton_interpolate};
+
+    #[test]
+    fn test_hermite_interpolate() {
+        // values and slopes of exp at 0 and 1, and the second derivative at 1
+        let e = std::f64::consts::E;
+        let p = hermite_interpolate(&[0.0, 1.0], &[vec![1.0, 1.0], vec![e, e, e]]);
+        println!("p = {p}");
+        assert!(p.degree() == 4);
+        let (y0, dy0) = p.eval_with_derivative(0.0);
+        let (y1, dy1) = p.eval_with_derivative(1.0);
+        assert!(y0.abs_diff_eq(&1.0, 1e-14) && dy0.abs_diff_eq(&1.0, 1e-14));
+        assert!(y1.abs_diff_eq(&e, 1e-14) && dy1.abs_diff_eq(&e, 1e-14));
+        let second = p.derivative().derivative();
+        assert!(second.nest_mul(1.0).abs_diff_eq(&e, 1e-12));
+        assert!(p.nest_mul(0.5).abs_diff_eq(&0.5_f64.exp(), 1e-3));
+    }
+
+    #[test]
+    fn test_values_only() {
+        // without derivatives it is the Lagrange interpolant
+        let (xs, ys) = ([0.0, 2.0, 3.0], [1.0, 2.0, 4.0]);
+        let data: Vec<Vec<f64>> = ys.iter().map(|y| vec![*y]).collect();
+        let p = hermite_interpolate(&xs, &data);
+        assert!(p.approx_eq(&newton_interpolate(&xs, &ys), 0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicated_node() {
+        hermite_interpolate(&[1.0, 1.0], &[vec![0.0], vec![1.0]]);
+    }
+}
@@ -0,0 +1,58 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{dim1_func::polynomial::Polynomial, float_traits::FloatConst};
+
+/// Interpolating polynomial through `(xs[i], ys[i])`, built as the sum of Lagrange basis
+/// polynomials `y_i * L_i(x)`, `L_i(x) = ∏_{j != i} (x - x_j) / (x_i - x_j)`, and given in
+/// Newton form with the nodes but the last as base points
+pub fn lagrange_interpolate<T>(xs: &[T], ys: &[T]) -> Polynomial<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialEq
+        + Copy,
+{
+    assert!(xs.len() == ys.len());
+    assert!(!xs.is_empty(), "no interpolation node");
+
+    let n = xs.len();
+    let mut sum = Polynomial::new(T::ZERO);
+    for i in 0..n {
+        let mut denom = T::ONE;
+        for j in 0..n {
+            if j != i {
+                assert!(xs[i] != xs[j], "duplicated interpolation node");
+                denom = denom * (xs[i] - xs[j]);
+            }
+        }
+        let others: Vec<T> = (0..n).filter(|j| *j != i).map(|j| xs[j]).collect();
+        let basis = Polynomial::new(ys[i] / denom).with_base_points(&others);
+        sum = &sum + &basis;
+    }
+
+    sum.to_newton_form(&xs[..n - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{dim1_func::Dim1Fn, interpolation::newton::newton_interpolate};
+
+    #[test]
+    fn test_lagrange_interpolate() {
+        let (xs, ys): ([f64; 3], [f64; 3]) = ([0.0, 2.0, 3.0], [1.0, 2.0, 4.0]);
+        let p = lagrange_interpolate(&xs, &ys);
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert!((p.eval(*x) - *y).abs() < 1e-14);
+        }
+        assert!(p.approx_eq(&newton_interpolate(&xs, &ys), 1e-14));
+
+        let xs = [-1.0, -0.3, 0.4, 0.8, 1.5, 2.0];
+        let ys: Vec<f64> = xs.iter().map(|x: &f64| x.exp()).collect();
+        assert!(lagrange_interpolate(&xs, &ys).approx_eq(&newton_interpolate(&xs, &ys), 1e-12));
+    }
+}
@@ -0,0 +1,161 @@
+use std::ops::{Div, Sub};
+
+use crate::dim1_func::polynomial::Polynomial;
+
+/// Newton divided difference interpolation, nodes can be added one at a time
+#[derive(Clone, Debug)]
+pub struct NewtonInterpolator<T> {
+    nodes: Vec<T>,
+    /// `f[x_0]`, `f[x_0, x_1]`, ..., `f[x_0 ... x_n]`
+    coefficients: Vec<T>,
+    /// `f[x_n]`, `f[x_{n-1}, x_n]`, ..., `f[x_0 ... x_n]`, the last diagonal of the
+    /// divided difference table
+    last_diagonal: Vec<T>,
+}
+
+impl<T> Default for NewtonInterpolator<T> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::default(),
+            coefficients: Vec::default(),
+            last_diagonal: Vec::default(),
+        }
+    }
+}
+
+impl<T> NewtonInterpolator<T>
+where
+    T: Sub<Output = T> + Div<Output = T> + PartialEq + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the node `(x, y)` in `O(n)`, without recomputing the divided difference table.
+    ///
+    /// Panics if `x` is already a node.
+    pub fn add_node(&mut self, x: T, y: T) {
+        assert!(!self.nodes.contains(&x), "duplicated interpolation node");
+
+        let n = self.nodes.len();
+        let mut diagonal = Vec::with_capacity(n + 1);
+        diagonal.push(y);
+        for j in 1..=n {
+            let d = (diagonal[j - 1] - self.last_diagonal[j - 1]) / (x - self.nodes[n - j]);
+            diagonal.push(d);
+        }
+
+        self.nodes.push(x);
+        self.coefficients.push(diagonal[n]);
+        self.last_diagonal = diagonal;
+    }
+
+    pub fn with_node(mut self, x: T, y: T) -> Self {
+        self.add_node(x, y);
+        self
+    }
+
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    /// Divided differences `f[x_0]`, `f[x_0, x_1]`, ..., `f[x_0 ... x_n]`
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Interpolating polynomial in Newton form: the divided differences are the
+    /// coefficients, the nodes but the last are the base points.
+    ///
+    /// Panics if there is no node.
+    pub fn polynomial(&self) -> Polynomial<T> {
+        let n = self.coefficients.len();
+        assert!(n > 0, "no interpolation node");
+        Polynomial::new(self.coefficients[n - 1])
+            .with_coefficients(&self.coefficients[..n - 1])
+            .with_base_points(&self.nodes[..n - 1])
+    }
+}
+
+/// Interpolating polynomial through `(xs[i], ys[i])` in Newton form
+pub fn newton_interpolate<T>(xs: &[T], ys: &[T]) -> Polynomial<T>
+where
+    T: Sub<Output = T> + Div<Output = T> + PartialEq + Copy,
+{
+    assert!(xs.len() == ys.len());
+    let mut interpolator = NewtonInterpolator::new();
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        interpolator.add_node(*x, *y);
+    }
+    interpolator.polynomial()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::{dim1_func::Dim1Fn, interpolation::lagrange::lagrange_interpolate};
+
+    #[test]
+    fn test_newton_interpolate() {
+        let (xs, ys) = ([0.0, 2.0, 3.0], [1.0, 2.0, 4.0]);
+        let p = newton_interpolate(&xs, &ys);
+        println!("p = {p}");
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert!(p.eval(*x) == *y);
+        }
+        // 1 + 1/2 x + 1/2 x (x - 2)
+        assert!(p.approx_eq(
+            &Polynomial::new(0.5)
+                .with_coefficients(&[1.0, 0.5])
+                .with_base_points(&[0.0, 2.0]),
+            0.0
+        ));
+
+        let p = newton_interpolate(&[1.5], &[-3.0]);
+        assert!(p.degree() == 0 && p.eval(10.0) == -3.0);
+    }
+
+    #[test]
+    fn test_add_node() {
+        let f = |x: f64| x.sin();
+        let xs = [0.1, 0.7, 1.2, 1.9, 2.4];
+        let mut interpolator = NewtonInterpolator::new();
+        for (i, x) in xs.iter().enumerate() {
+            interpolator.add_node(*x, f(*x));
+            let p = interpolator.polynomial();
+            for x in &xs[..=i] {
+                assert!(
+                    p.eval(*x).abs_diff_eq(&f(*x), 1e-14),
+                    "p({x}) = {}",
+                    p.eval(*x)
+                );
+            }
+            let ys: Vec<f64> = xs[..=i].iter().map(|x| f(*x)).collect();
+            assert!(p.approx_eq(&lagrange_interpolate(&xs[..=i], &ys), 1e-12));
+        }
+        assert!(interpolator.nodes() == xs);
+        assert!(interpolator
+            .polynomial()
+            .eval(1.0)
+            .abs_diff_eq(&f(1.0), 1e-3));
+
+        // f[0] = 1, f[0, 2] = 1/2, f[2, 3] = 2, f[0, 2, 3] = (2 - 1/2) / 3
+        let mut interpolator = NewtonInterpolator::new().with_node(0.0, 1.0);
+        assert!(interpolator.coefficients() == [1.0]);
+        interpolator.add_node(2.0, 2.0);
+        assert!(interpolator.coefficients() == [1.0, 0.5]);
+        interpolator.add_node(3.0, 4.0);
+        assert!(interpolator.coefficients() == [1.0, 0.5, 0.5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicated_node() {
+        NewtonInterpolator::new()
+            .with_node(1.0, 2.0)
+            .with_node(1.0, 3.0);
+    }
+}
@@ -0,0 +1,313 @@
+use std::ops::{Add, Div, Mul, Neg, Range, Sub};
+
+use crate::{
+    dim1_func::{polynomial::Polynomial, Dim1Fn},
+    float_traits::{fl, Abs, FloatConst, FromF64, MaxMin, Trig},
+};
+
+use super::{barycentric::BarycentricInterpolant, chebyshev::chebyshev_nodes};
+
+const GOLDEN_ITER_LIMIT: usize = 100;
+
+/// The nodal polynomial `w(x) = (x - x_0)...(x - x_{n-1})` of the interpolation error
+/// `f(x) - P(x) = f^(n)(c) / n! * w(x)`, in Newton form with the nodes as base points
+pub fn nodal_polynomial<T: FloatConst + Clone>(nodes: &[T]) -> Polynomial<T> {
+    Polynomial::new(T::ONE).with_base_points(nodes)
+}
+
+/// `max |w(x)|` over `range` for the nodal polynomial `w` of `nodes`.
+///
+/// Between two consecutive nodes `|w|` has a single maximum, which is located by golden
+/// section search; outside the nodes `|w|` is monotone, so the ends of `range` are checked.
+pub fn nodal_max_norm<T>(nodes: &[T], range: Range<T>) -> T
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Copy,
+{
+    let w = nodal_polynomial(nodes);
+    piecewise_max(|x| w.eval(x).abs(), nodes, range)
+}
+
+/// Lebesgue constant `max Σ |l_i(x)|` over `range` of the Lagrange basis `l_i` of `nodes`,
+/// the factor by which the interpolation error may exceed the best polynomial
+/// approximation error. The Lebesgue function is evaluated in barycentric form.
+pub fn lebesgue_constant<T>(nodes: &[T], range: Range<T>) -> T
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Copy,
+{
+    let weights = BarycentricInterpolant::lagrange(nodes, &vec![T::ZERO; nodes.len()])
+        .weights()
+        .to_vec();
+    let lebesgue = |x: T| {
+        let (mut num, mut den) = (T::ZERO, T::ZERO);
+        for (xi, wi) in nodes.iter().zip(weights.iter()) {
+            let dx = x - *xi;
+            if dx == T::ZERO {
+                return T::ONE;
+            }
+            num = num + (*wi / dx).abs();
+            den = den + *wi / dx;
+        }
+        num / den.abs()
+    };
+    piecewise_max(lebesgue, nodes, range)
+}
+
+/// Maximum of `f` over `range`, where `f` is unimodal between consecutive `nodes` and
+/// monotone outside of them
+fn piecewise_max<T, F>(f: F, nodes: &[T], range: Range<T>) -> T
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Copy,
+    F: Fn(T) -> T,
+{
+    let (a, b) = (range.start, range.end);
+    assert!(a <= b);
+    let mut points = vec![a, b];
+    points.extend(nodes.iter().filter(|x| **x > a && **x < b));
+    points.sort_by(|x0, x1| x0.partial_cmp(x1).unwrap());
+
+    let mut max = f(a).max(f(b));
+    for w in points.windows(2) {
+        max = max.max(golden_section_max(&f, w[0], w[1]));
+    }
+    max
+}
+
+fn golden_section_max<T, F>(f: &F, mut a: T, mut b: T) -> T
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Copy,
+    F: Fn(T) -> T,
+{
+    // 1 / golden ratio
+    let r: T = fl!(0.618_033_988_749_894_9);
+    let mut x0 = b - (b - a) * r;
+    let mut x1 = a + (b - a) * r;
+    let (mut f0, mut f1) = (f(x0), f(x1));
+    for _ in 0..GOLDEN_ITER_LIMIT {
+        if b - a <= T::EPSILON * (a.abs().max(b.abs())).max(T::ONE) {
+            break;
+        }
+        if f0 < f1 {
+            a = x0;
+            (x0, f0) = (x1, f1);
+            x1 = a + (b - a) * r;
+            f1 = f(x1);
+        } else {
+            b = x1;
+            (x1, f1) = (x0, f0);
+            x0 = b - (b - a) * r;
+            f0 = f(x0);
+        }
+    }
+    f0.max(f1)
+}
+
+/// Node placements to compare with [`compare_node_sets`]
+#[derive(Clone, Debug)]
+pub enum NodeSet<T> {
+    /// `n` equally spaced nodes including both ends of the range
+    Equispaced,
+    /// The `n` Chebyshev nodes of the range, see [`chebyshev_nodes`]
+    Chebyshev,
+    /// Given nodes, the requested node count is ignored
+    Custom(Vec<T>),
+}
+
+impl<T> NodeSet<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FromF64
+        + Trig
+        + Copy,
+{
+    pub fn nodes(&self, n: usize, range: Range<T>) -> Vec<T> {
+        let (a, b) = (range.start, range.end);
+        match self {
+            NodeSet::Equispaced if n == 1 => vec![(a + b) / fl!(2.0)],
+            NodeSet::Equispaced => (0..n)
+                .map(|i| {
+                    if i == n - 1 {
+                        b
+                    } else {
+                        a + (b - a) * T::from_f64(i as f64) / T::from_f64((n - 1) as f64)
+                    }
+                })
+                .collect(),
+            NodeSet::Chebyshev => chebyshev_nodes(n, range),
+            NodeSet::Custom(nodes) => nodes.clone(),
+        }
+    }
+}
+
+/// Error figures of a node set on an interval
+#[derive(Clone, Debug)]
+pub struct NodeSetReport<T> {
+    nodes: Vec<T>,
+    nodal_max_norm: T,
+    lebesgue_constant: T,
+}
+
+impl<T> NodeSetReport<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Copy,
+{
+    pub fn new(nodes: &[T], range: Range<T>) -> Self {
+        Self {
+            nodes: nodes.to_vec(),
+            nodal_max_norm: nodal_max_norm(nodes, range.clone()),
+            lebesgue_constant: lebesgue_constant(nodes, range),
+        }
+    }
+
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    /// `max |(x - x_0)...(x - x_{n-1})|` over the interval
+    pub fn nodal_max_norm(&self) -> T {
+        self.nodal_max_norm
+    }
+
+    pub fn lebesgue_constant(&self) -> T {
+        self.lebesgue_constant
+    }
+
+    /// Bound of the interpolation error `max|f^(n)| / n! * max|w|`, where
+    /// `max_nth_derivative` bounds `|f^(n)|` on the interval and `n` is the node count
+    pub fn error_bound(&self, max_nth_derivative: T) -> T {
+        let mut bound = max_nth_derivative * self.nodal_max_norm;
+        for i in 1..=self.nodes.len() {
+            bound = bound / T::from_f64(i as f64);
+        }
+        bound
+    }
+}
+
+/// Reports the nodal polynomial max norm and the Lebesgue constant of every node set with
+/// `n` nodes on `range`, in the order of `sets`
+pub fn compare_node_sets<T>(sets: &[NodeSet<T>], n: usize, range: Range<T>) -> Vec<NodeSetReport<T>>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + MaxMin
+        + FloatConst
+        + FromF64
+        + Abs
+        + Trig
+        + Copy,
+{
+    sets.iter()
+        .map(|set| NodeSetReport::new(&set.nodes(n, range.clone()), range.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    #[test]
+    fn test_nodal_polynomial() {
+        let w = nodal_polynomial(&[0.0, 1.0, 2.0]);
+        assert!(w.degree() == 3);
+        assert!(w.eval(3.0) == 6.0 && w.eval(1.0) == 0.0);
+
+        // the minimal max norm 1 / 2^(n-1) of the Chebyshev nodes on [-1, 1]
+        let norm = nodal_max_norm(&chebyshev_nodes(8, -1.0..1.0), -1.0..1.0);
+        assert!(norm.abs_diff_eq(&(1.0 / 128.0), 1e-15));
+
+        // x (x - 1) on [0, 1] peaks at 1 / 2, and at the ends of [-1, 1]
+        assert!(nodal_max_norm(&[0.0, 1.0], 0.0..1.0).abs_diff_eq(&0.25, 1e-15));
+        assert!(nodal_max_norm(&[0.0, 1.0], -1.0..1.0) == 2.0);
+    }
+
+    #[test]
+    fn test_lebesgue_constant() {
+        // linear interpolation at the ends never amplifies
+        assert!(lebesgue_constant(&[-1.0, 1.0], -1.0..1.0).abs_diff_eq(&1.0, 1e-15));
+        // quadratic interpolation at -1, 0, 1 has Λ = 5 / 4
+        assert!(lebesgue_constant(&[-1.0, 0.0, 1.0], -1.0..1.0).abs_diff_eq(&1.25, 1e-14));
+    }
+
+    #[test]
+    fn test_compare_node_sets() {
+        let custom = NodeSet::Custom(vec![-1.0, -0.9, -0.5, 0.0, 0.5, 0.9, 1.0]);
+        let reports = compare_node_sets(
+            &[NodeSet::Equispaced, NodeSet::Chebyshev, custom],
+            11,
+            -1.0..1.0,
+        );
+        for (name, report) in ["equispaced", "chebyshev", "custom"].iter().zip(&reports) {
+            println!(
+                "{name}: n = {}, max |w| = {:e}, lebesgue constant = {}, error bound = {:e}",
+                report.nodes().len(),
+                report.nodal_max_norm(),
+                report.lebesgue_constant(),
+                report.error_bound(1.0)
+            );
+        }
+        let (equispaced, chebyshev) = (&reports[0], &reports[1]);
+        assert!(reports[2].nodes().len() == 7);
+        assert!(chebyshev
+            .nodal_max_norm()
+            .abs_diff_eq(&(1.0 / 1024.0), 1e-15));
+        assert!(equispaced.nodal_max_norm() > chebyshev.nodal_max_norm());
+        // Λ of 11 equispaced nodes is about 29.9, and about 2.49 for the Chebyshev nodes
+        assert!(equispaced.lebesgue_constant().abs_diff_eq(&29.89, 0.01));
+        assert!(chebyshev.lebesgue_constant().abs_diff_eq(&2.49, 0.01));
+    }
+}
@@ -0,0 +1,94 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::float_traits::{fl, FloatConst, FromF64, Sqrt};
+
+use super::piecewise::PiecewiseCubic;
+
+/// Shape preserving piecewise cubic Hermite interpolation with the Fritsch–Carlson slopes.
+///
+/// The interpolant is monotone wherever the data is, and never overshoots the data: the
+/// slope is zero at local extrema of the data, and the slopes of every interval are
+/// scaled into the circle `α^2 + β^2 <= 9` of monotone cubics. `knots` must be strictly
+/// increasing, with at least 2 of them.
+pub fn pchip_interpolate<T>(knots: &[T], values: &[T]) -> PiecewiseCubic<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Sqrt
+        + Copy,
+{
+    assert!(knots.len() == values.len());
+    assert!(knots.len() >= 2, "a piecewise cubic needs at least 2 knots");
+    let n = knots.len() - 1;
+    let d: Vec<T> = (0..n)
+        .map(|i| (values[i + 1] - values[i]) / (knots[i + 1] - knots[i]))
+        .collect();
+
+    let mut slopes = vec![T::ZERO; n + 1];
+    slopes[0] = d[0];
+    slopes[n] = d[n - 1];
+    for i in 1..n {
+        if d[i - 1] * d[i] > T::ZERO {
+            slopes[i] = (d[i - 1] + d[i]) / fl!(2.0);
+        }
+    }
+
+    for i in 0..n {
+        if d[i] == T::ZERO {
+            slopes[i] = T::ZERO;
+            slopes[i + 1] = T::ZERO;
+            continue;
+        }
+        let (alpha, beta) = (slopes[i] / d[i], slopes[i + 1] / d[i]);
+        let r = alpha * alpha + beta * beta;
+        if r > fl!(9.0) {
+            let tau = T::from_f64(3.0) / r.sqrt();
+            slopes[i] = tau * alpha * d[i];
+            slopes[i + 1] = tau * beta * d[i];
+        }
+    }
+
+    PiecewiseCubic::new(knots, values, &slopes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::dim1_func::Dim1Fn;
+
+    #[test]
+    fn test_monotone_data() {
+        // a steep step, where a spline would overshoot
+        let knots = [0.0, 1.0, 2.0, 3.0, 3.1, 4.0, 5.0];
+        let values = [0.0, 0.0, 0.1, 0.2, 5.0, 5.0, 5.1];
+        let p = pchip_interpolate(&knots, &values);
+
+        let mut last = p.eval(0.0);
+        for i in 1..=500 {
+            let y = p.eval(i as f64 * 0.01);
+            assert!(y >= last - 1e-15, "not monotone at {}", i as f64 * 0.01);
+            assert!((0.0..=5.1 + 1e-15).contains(&y));
+            last = y;
+        }
+        for (x, y) in knots.iter().zip(values) {
+            assert!(p.eval(*x).abs_diff_eq(&y, 1e-14));
+        }
+        // flat on the flat intervals
+        assert!(p.eval(0.5) == 0.0 && p.eval(3.5) == 5.0);
+    }
+
+    #[test]
+    fn test_extremum() {
+        let p = pchip_interpolate(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.0]);
+        assert!(p.slopes()[1] == 0.0);
+        assert!((0..=20).all(|i| p.eval(i as f64 * 0.1) <= 1.0));
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::{Dim1Fn, Dim1FnWithDerivative},
+    float_traits::{fl, FloatConst, FromF64},
+};
+
+/// Piecewise cubic in Hermite form, given by its values and slopes at the knots.
+/// It has a continuous first derivative, and is extended beyond the end knots by the
+/// cubics of the end intervals.
+#[derive(Clone, Debug)]
+pub struct PiecewiseCubic<T> {
+    knots: Vec<T>,
+    values: Vec<T>,
+    slopes: Vec<T>,
+}
+
+impl<T> PiecewiseCubic<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    /// `knots` must be strictly increasing, with at least 2 of them
+    pub fn new(knots: &[T], values: &[T], slopes: &[T]) -> Self {
+        assert!(knots.len() == values.len() && knots.len() == slopes.len());
+        assert!(knots.len() >= 2, "a piecewise cubic needs at least 2 knots");
+        assert!(
+            knots.windows(2).all(|w| w[0] < w[1]),
+            "knots must be strictly increasing"
+        );
+        Self {
+            knots: knots.to_vec(),
+            values: values.to_vec(),
+            slopes: slopes.to_vec(),
+        }
+    }
+
+    pub fn knots(&self) -> &[T] {
+        &self.knots
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn slopes(&self) -> &[T] {
+        &self.slopes
+    }
+
+    /// Index of the interval containing `x`, the end intervals extend to infinity
+    fn interval(&self, x: T) -> usize {
+        let n = self.knots.len() - 1;
+        self.knots.partition_point(|k| *k <= x).clamp(1, n) - 1
+    }
+
+    /// `(x_i, y_i, s_i)` at both ends of the interval containing `x`
+    fn ends(&self, x: T) -> ((T, T, T), (T, T, T)) {
+        let i = self.interval(x);
+        (
+            (self.knots[i], self.values[i], self.slopes[i]),
+            (self.knots[i + 1], self.values[i + 1], self.slopes[i + 1]),
+        )
+    }
+
+    pub fn derivative(&self, x: T) -> T {
+        let (start, end) = self.ends(x);
+        hermite_cubic(start, end, x).1
+    }
+
+    pub fn second_derivative(&self, x: T) -> T {
+        let (start, end) = self.ends(x);
+        let (_, c2, c3) = hermite_coefficients(start, end);
+        c2 * fl!(2.0) + c3 * fl!(6.0) * (x - start.0)
+    }
+}
+
+/// `(s0, c2, c3)` of the cubic `y0 + s0 dx + c2 dx^2 + c3 dx^3`, `dx = x - x0`, with
+/// values `y0, y1` and slopes `s0, s1` at `x0, x1`
+fn hermite_coefficients<T>((x0, y0, s0): (T, T, T), (x1, y1, s1): (T, T, T)) -> (T, T, T)
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + FromF64 + Copy,
+{
+    let h = x1 - x0;
+    let d = (y1 - y0) / h;
+    let c2 = (d * fl!(3.0) - s0 * fl!(2.0) - s1) / h;
+    let c3 = (s0 + s1 - d * fl!(2.0)) / (h * h);
+    (s0, c2, c3)
+}
+
+/// Value and slope at `x` of the cubic through `(x0, y0)` and `(x1, y1)` with slopes `s0`
+/// and `s1` there, given as `(x0, y0, s0)` and `(x1, y1, s1)`
+pub(crate) fn hermite_cubic<T>(start: (T, T, T), end: (T, T, T), x: T) -> (T, T)
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + FromF64 + Copy,
+{
+    let (s, c2, c3) = hermite_coefficients(start, end);
+    let (dx, y0) = (x - start.0, start.1);
+    (
+        y0 + (s + (c2 + c3 * dx) * dx) * dx,
+        s + (c2 * fl!(2.0) + c3 * fl!(3.0) * dx) * dx,
+    )
+}
+
+impl<T> Dim1Fn<T> for PiecewiseCubic<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    fn eval(&self, x: T) -> T {
+        let (start, end) = self.ends(x);
+        hermite_cubic(start, end, x).0
+    }
+}
+
+impl<T> Dim1FnWithDerivative<T> for PiecewiseCubic<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    fn eval_with_derivative(&self, x: T) -> (T, T) {
+        let (start, end) = self.ends(x);
+        hermite_cubic(start, end, x)
+    }
+}
+
+impl<T> ContinuousFn for PiecewiseCubic<T> where PiecewiseCubic<T>: Dim1Fn<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    #[test]
+    fn test_hermite_data() {
+        let knots = [0.0, 1.0, 3.0];
+        let f = |x: f64| (x * x * x - x, 3.0 * x * x - 1.0);
+        let values: Vec<f64> = knots.iter().map(|x| f(*x).0).collect();
+        let slopes: Vec<f64> = knots.iter().map(|x| f(*x).1).collect();
+        let p = PiecewiseCubic::new(&knots, &values, &slopes);
+
+        // a single cubic is reproduced, including beyond the end knots
+        for i in 0..=40 {
+            let x = -0.5 + i as f64 * 0.1;
+            let (y, dy) = p.eval_with_derivative(x);
+            assert!(y.abs_diff_eq(&f(x).0, 1e-12));
+            assert!(dy.abs_diff_eq(&f(x).1, 1e-12));
+            assert!(p.second_derivative(x).abs_diff_eq(&(6.0 * x), 1e-12));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    continuous_func::ContinuousFn,
+    dim1_func::{Dim1Fn, Dim1FnWithDerivative},
+    float_traits::{fl, FloatConst, FromF64},
+    matrix::tridiagonal::TridiagonalMatrix,
+};
+
+use super::piecewise::PiecewiseCubic;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplineEndCondition<T> {
+    /// Zero second derivative at both ends
+    Natural,
+    /// Given first derivatives at the first and the last knot
+    Clamped(T, T),
+    /// Continuous third derivative at the second and the second last knot
+    NotAKnot,
+    /// Matching first and second derivatives at both ends. The first and the last value
+    /// must agree up to rounding, the last one is then taken to be the first one
+    Periodic,
+}
+
+/// Piecewise cubic interpolation with continuous second derivative
+#[derive(Clone, Debug)]
+pub struct CubicSpline<T> {
+    cubic: PiecewiseCubic<T>,
+}
+
+impl<T> CubicSpline<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    /// Builds the spline through `(knots[i], values[i])`, `knots` must be strictly
+    /// increasing. At least 2 knots are needed, and 4 for the periodic condition.
+    ///
+    /// Panics if the periodic condition is given with first and last values further apart
+    /// than `1e-10 * max(|values[0]|, 1)`.
+    pub fn new(knots: &[T], values: &[T], end_condition: SplineEndCondition<T>) -> Self {
+        assert!(knots.len() == values.len());
+        assert!(knots.len() >= 2, "a spline needs at least 2 knots");
+        assert!(
+            knots.windows(2).all(|w| w[0] < w[1]),
+            "spline knots must be strictly increasing"
+        );
+
+        let mut values = values.to_vec();
+        if end_condition == SplineEndCondition::Periodic {
+            let (first, last) = (values[0], values[knots.len() - 1]);
+            let scale = if first < -T::ONE {
+                -first
+            } else if first > T::ONE {
+                first
+            } else {
+                T::ONE
+            };
+            let tol = scale * fl!(1e-10);
+            assert!(
+                last - first <= tol && first - last <= tol,
+                "periodic spline needs equal first and last values"
+            );
+            values[knots.len() - 1] = first;
+        }
+
+        // slopes at the knots from the second derivatives M,
+        // s_i = d_i - h_i (2 M_i + M_{i+1}) / 6 and s_n = d_{n-1} + h_{n-1} (M_{n-1} + 2 M_n) / 6
+        let m = moments(knots, &values, end_condition);
+        let n = knots.len() - 1;
+        let mut slopes: Vec<T> = (0..n)
+            .map(|i| {
+                let h = knots[i + 1] - knots[i];
+                (values[i + 1] - values[i]) / h - (m[i] * fl!(2.0) + m[i + 1]) * h / fl!(6.0)
+            })
+            .collect();
+        let h = knots[n] - knots[n - 1];
+        slopes.push((values[n] - values[n - 1]) / h + (m[n - 1] + m[n] * fl!(2.0)) * h / fl!(6.0));
+
+        Self {
+            cubic: PiecewiseCubic::new(knots, &values, &slopes),
+        }
+    }
+
+    pub fn knots(&self) -> &[T] {
+        self.cubic.knots()
+    }
+
+    /// The spline as a [`PiecewiseCubic`] in Hermite form
+    pub fn as_piecewise_cubic(&self) -> &PiecewiseCubic<T> {
+        &self.cubic
+    }
+
+    pub fn derivative(&self, x: T) -> T {
+        self.cubic.derivative(x)
+    }
+
+    pub fn second_derivative(&self, x: T) -> T {
+        self.cubic.second_derivative(x)
+    }
+}
+
+impl<T> Dim1Fn<T> for CubicSpline<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    fn eval(&self, x: T) -> T {
+        self.cubic.eval(x)
+    }
+}
+
+impl<T> Dim1FnWithDerivative<T> for CubicSpline<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    fn eval_with_derivative(&self, x: T) -> (T, T) {
+        self.cubic.eval_with_derivative(x)
+    }
+}
+
+impl<T> ContinuousFn for CubicSpline<T> where CubicSpline<T>: Dim1Fn<T> {}
+
+/// Second derivatives at the knots, from the tridiagonal continuity equations
+/// `h_{i-1} M_{i-1} + 2 (h_{i-1} + h_i) M_i + h_i M_{i+1} = 6 (d_i - d_{i-1})`
+fn moments<T>(knots: &[T], values: &[T], end_condition: SplineEndCondition<T>) -> Vec<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + FromF64
+        + PartialOrd
+        + Copy,
+{
+    let n = knots.len() - 1;
+    let h: Vec<T> = knots.windows(2).map(|w| w[1] - w[0]).collect();
+    let d: Vec<T> = (0..n).map(|i| (values[i + 1] - values[i]) / h[i]).collect();
+    let rhs = |i: usize| (d[i] - d[i - 1]) * fl!(6.0);
+
+    // the interior equations, for M_1 ... M_{n-1}
+    let mut lower: Vec<T> = (2..n).map(|i| h[i - 1]).collect();
+    let mut diag: Vec<T> = (1..n).map(|i| (h[i - 1] + h[i]) * fl!(2.0)).collect();
+    let mut upper: Vec<T> = (1..(n - 1).max(1)).map(|i| h[i]).collect();
+    let mut b: Vec<T> = (1..n).map(rhs).collect();
+
+    match end_condition {
+        SplineEndCondition::Natural => {
+            if n == 1 {
+                return vec![T::ZERO; 2];
+            }
+            let mut m = TridiagonalMatrix::new(lower, diag, upper).solve(&b);
+            m.insert(0, T::ZERO);
+            m.push(T::ZERO);
+            m
+        }
+        SplineEndCondition::Clamped(s0, s1) => {
+            lower.insert(0, h[0]);
+            lower.push(h[n - 1]);
+            diag.insert(0, h[0] * fl!(2.0));
+            diag.push(h[n - 1] * fl!(2.0));
+            upper.insert(0, h[0]);
+            upper.push(h[n - 1]);
+            b.insert(0, (d[0] - s0) * fl!(6.0));
+            b.push((s1 - d[n - 1]) * fl!(6.0));
+            if n == 1 {
+                // a single interval, the interior equations are empty
+                lower.truncate(1);
+                upper.truncate(1);
+            }
+            TridiagonalMatrix::new(lower, diag, upper).solve(&b)
+        }
+        SplineEndCondition::NotAKnot => {
+            if n < 3 {
+                // the spline is the interpolating polynomial of degree n
+                let m = if n == 2 {
+                    (d[1] - d[0]) / (knots[2] - knots[0]) * fl!(2.0)
+                } else {
+                    T::ZERO
+                };
+                return vec![m; n + 1];
+            }
+            // M_0 = ((h_0 + h_1) M_1 - h_0 M_2) / h_1, substituted into the first equation
+            diag[0] = (h[0] + h[1]) * (h[0] + h[1] * fl!(2.0)) / h[1];
+            upper[0] = (h[1] - h[0]) * (h[1] + h[0]) / h[1];
+            // and symmetrically for M_n into the last one
+            let (p, q) = (h[n - 2], h[n - 1]);
+            diag[n - 2] = (p + q) * (p * fl!(2.0) + q) / p;
+            lower[n - 3] = (p - q) * (p + q) / p;
+
+            let mut m = TridiagonalMatrix::new(lower, diag, upper).solve(&b);
+            let m0 = ((h[0] + h[1]) * m[0] - h[0] * m[1]) / h[1];
+            let mn = ((p + q) * m[n - 2] - q * m[n - 3]) / p;
+            m.insert(0, m0);
+            m.push(mn);
+            m
+        }
+        SplineEndCondition::Periodic => {
+            assert!(n >= 3, "a periodic spline needs at least 4 knots");
+            // M_0 ... M_{n-1} with M_n = M_0, the equation of knot 0 wraps around
+            lower.insert(0, h[0]);
+            diag.insert(0, (h[n - 1] + h[0]) * fl!(2.0));
+            upper.insert(0, h[0]);
+            b.insert(0, (d[0] - d[n - 1]) * fl!(6.0));
+
+            let mut m =
+                TridiagonalMatrix::new(lower, diag, upper).solve_cyclic(h[n - 1], h[n - 1], &b);
+            m.push(m[0]);
+            m
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    const KNOTS: [f64; 6] = [-1.0, -0.4, 0.0, 0.7, 1.5, 2.0];
+
+    fn cubic(x: f64) -> (f64, f64, f64) {
+        (x * x * x - 2.0 * x + 1.0, 3.0 * x * x - 2.0, 6.0 * x)
+    }
+
+    fn check_reproduces_cubic(spline: &CubicSpline<f64>) {
+        for i in 0..=60 {
+            let x = -1.0 + i as f64 * 0.05;
+            let (y, dy, ddy) = cubic(x);
+            assert!(spline.eval(x).abs_diff_eq(&y, 1e-12));
+            assert!(spline.derivative(x).abs_diff_eq(&dy, 1e-12));
+            assert!(spline.second_derivative(x).abs_diff_eq(&ddy, 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_natural() {
+        let ys = [1.0, 3.0, 2.0, -1.0, 0.0, 0.5];
+        let spline = CubicSpline::new(&KNOTS, &ys, SplineEndCondition::Natural);
+        for (x, y) in KNOTS.iter().zip(ys) {
+            assert!(spline.eval(*x).abs_diff_eq(&y, 1e-14));
+        }
+        assert!(spline.second_derivative(-1.0).abs_diff_eq(&0.0, 1e-14));
+        assert!(spline.second_derivative(2.0).abs_diff_eq(&0.0, 1e-14));
+
+        // a line through two points
+        let spline = CubicSpline::new(&[0.0, 2.0], &[1.0, 5.0], SplineEndCondition::Natural);
+        assert!(spline.eval(0.5) == 2.0 && spline.derivative(1.5) == 2.0);
+    }
+
+    #[test]
+    fn test_clamped_and_not_a_knot() {
+        let ys: Vec<f64> = KNOTS.iter().map(|x| cubic(*x).0).collect();
+        let clamped = SplineEndCondition::Clamped(cubic(-1.0).1, cubic(2.0).1);
+        check_reproduces_cubic(&CubicSpline::new(&KNOTS, &ys, clamped));
+        check_reproduces_cubic(&CubicSpline::new(&KNOTS, &ys, SplineEndCondition::NotAKnot));
+        check_reproduces_cubic(&CubicSpline::new(
+            &KNOTS[..4],
+            &ys[..4],
+            SplineEndCondition::NotAKnot,
+        ));
+
+        // with 3 knots the not-a-knot spline is the interpolating parabola
+        let spline = CubicSpline::new(
+            &[0.0, 1.0, 3.0],
+            &[0.0, 1.0, 9.0],
+            SplineEndCondition::NotAKnot,
+        );
+        assert!(spline.eval(2.0).abs_diff_eq(&4.0, 1e-14));
+    }
+
+    #[test]
+    fn test_periodic() {
+        let n = 16;
+        let knots: Vec<f64> = (0..=n)
+            .map(|i| 2.0 * std::f64::consts::PI * i as f64 / n as f64)
+            .collect();
+        let ys: Vec<f64> = knots.iter().map(|x| x.sin()).collect();
+        let spline = CubicSpline::new(&knots, &ys, SplineEndCondition::Periodic);
+
+        let end = knots[n];
+        assert!(spline
+            .derivative(0.0)
+            .abs_diff_eq(&spline.derivative(end), 1e-12));
+        assert!(spline
+            .second_derivative(0.0)
+            .abs_diff_eq(&spline.second_derivative(end), 1e-12));
+        for i in 0..100 {
+            let x = end * i as f64 / 100.0;
+            assert!(spline.eval(x).abs_diff_eq(&x.sin(), 1e-3));
+            assert!(spline.derivative(x).abs_diff_eq(&x.cos(), 1e-2));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_periodic_mismatch() {
+        let knots = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys = [0.0, 1.0, 0.0, -1.0, 0.5];
+        CubicSpline::new(&knots, &ys, SplineEndCondition::Periodic);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod complex;
 pub mod float_traits;
 
 pub mod continuous_func;
@@ -5,4 +6,6 @@ pub mod dim1_func;
 
 pub mod dim1_equation_solve;
 
+pub mod interpolation;
+
 pub mod matrix;
@@ -1,4 +1,15 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+pub mod cholesky;
+mod eigen;
+pub mod lu;
+mod norm;
+mod svd;
+mod triangular;
+pub mod tridiagonal;
+
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
 
 use crate::float_traits::{FloatConst, FromF64};
 
@@ -8,6 +19,30 @@ pub enum MatrixMajor {
     Column,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// A zero pivot showed up, the matrix is singular
+    Singular,
+    NotSquare,
+    /// A non-positive pivot showed up in the Cholesky factorization
+    NotPositiveDefinite,
+    /// Operand sizes do not agree
+    DimensionMismatch,
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NotSquare => write!(f, "matrix is not square"),
+            MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MatrixError::DimensionMismatch => write!(f, "dimensions do not match"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
 #[derive(Debug, Clone)]
 pub struct FullMatrix<T> {
     major: MatrixMajor,
@@ -19,7 +54,7 @@ impl<T> FullMatrix<T> {
     pub fn new(major: MatrixMajor, major_dim: usize, array: Vec<T>) -> Self {
         assert!(major_dim > 0);
         assert!(!array.is_empty());
-        assert!(array.len() % major_dim == 0);
+        assert!(array.len().is_multiple_of(major_dim));
         Self {
             major,
             array,
@@ -40,6 +75,21 @@ impl<T> FullMatrix<T> {
         Self::new(MatrixMajor::Row, n, v)
     }
 
+    pub fn identity(n: usize) -> Self
+    where
+        T: FloatConst + Clone,
+    {
+        let mut v = vec![T::ZERO; n * n];
+        for i in 0..n {
+            v[i * n + i] = T::ONE;
+        }
+        Self::new(MatrixMajor::Row, n, v)
+    }
+
+    pub fn major(&self) -> MatrixMajor {
+        self.major
+    }
+
     fn dim(&self) -> (usize, usize) {
         (self.array.len() / self.major_dim, self.major_dim)
     }
@@ -75,28 +125,60 @@ impl<T> FullMatrix<T> {
         self.array[non_major * self.major_dim + major] = val;
     }
 
+    /// Entry at `row` and `col`, whatever the layout
+    pub fn entry(&self, row: usize, col: usize) -> T
+    where
+        T: Copy,
+    {
+        match self.major {
+            MatrixMajor::Row => self.get(row, col),
+            MatrixMajor::Column => self.get(col, row),
+        }
+    }
+
+    pub fn set_entry(&mut self, row: usize, col: usize, val: T) {
+        match self.major {
+            MatrixMajor::Row => self.set(row, col, val),
+            MatrixMajor::Column => self.set(col, row, val),
+        }
+    }
+
+    /// `Av`, by dot products with the rows of a row-major matrix and by a linear
+    /// combination of the columns of a column-major one, so that the inner loop is
+    /// contiguous in memory
     pub fn mul_vec(&self, v: &[T]) -> Vec<T>
     where
         T: Copy + Mul<Output = T> + Add<Output = T> + FloatConst,
     {
+        assert!(v.len() == self.column_count());
         match self.major {
             MatrixMajor::Row => {
-                assert!(v.len() == self.column_count());
                 let mut ret = Vec::default();
                 for i in 0..self.row_count() {
                     let mut xi = T::ZERO;
-                    for j in 0..self.column_count() {
-                        xi = xi + self.get(i, j);
+                    for (j, vj) in v.iter().enumerate() {
+                        xi = xi + self.get(i, j) * *vj;
                     }
                     ret.push(xi);
                 }
 
                 ret
             }
-            MatrixMajor::Column => unimplemented!("not memory efficient for column major matrix"),
+            MatrixMajor::Column => {
+                let mut ret = vec![T::ZERO; self.row_count()];
+                for (j, vj) in v.iter().enumerate() {
+                    for (i, xi) in ret.iter_mut().enumerate() {
+                        *xi = *xi + self.get(j, i) * *vj;
+                    }
+                }
+
+                ret
+            }
         }
     }
 
+    /// Solves `Ax = b` by Gaussian elimination without pivoting. A row-major matrix is
+    /// eliminated row by row, a column-major one column by column.
     pub fn gaussian_elimination(&self, b: &[T]) -> Vec<T>
     where
         T: Clone
@@ -108,15 +190,15 @@ impl<T> FullMatrix<T> {
             + Sub<Output = T>
             + FloatConst,
     {
-        match self.major {
-            MatrixMajor::Row => {
-                assert!(self.is_square_matrix());
-                assert!(b.len() == self.column_count());
+        assert!(self.is_square_matrix());
+        assert!(b.len() == self.column_count());
 
-                let mut b = b.to_owned();
-                let mut a = self.clone();
-                let n = b.len();
+        let mut b = b.to_owned();
+        let mut a = self.clone();
+        let n = b.len();
 
+        match self.major {
+            MatrixMajor::Row => {
                 for col in 0..n {
                     let p = a.get(col, col);
                     for row in (col + 1)..n {
@@ -142,15 +224,94 @@ impl<T> FullMatrix<T> {
 
                 x
             }
-            MatrixMajor::Column => unimplemented!("not memory efficient for column major matrix"),
+            MatrixMajor::Column => {
+                // a.get(j, i) is the entry at row i and column j
+                for col in 0..n {
+                    let p = a.get(col, col);
+                    for row in (col + 1)..n {
+                        let l = a.get(col, row) / p;
+                        a.set(col, row, l);
+                        b[row] = b[row] - l * b[col];
+                    }
+                    for k in (col + 1)..n {
+                        let pivot_row_k = a.get(k, col);
+                        for row in (col + 1)..n {
+                            let old = a.get(k, row);
+                            a.set(k, row, old - a.get(col, row) * pivot_row_k);
+                        }
+                    }
+                }
+
+                // column oriented back substitution
+                for j in (0..n).rev() {
+                    b[j] = b[j] / a.get(j, j);
+                    for i in 0..j {
+                        b[i] = b[i] - a.get(j, i) * b[j];
+                    }
+                }
+
+                b
+            }
+        }
+    }
+
+    /// `A^T` without copying, the same data read in the other layout
+    pub fn transpose(mut self) -> Self {
+        self.major = match self.major {
+            MatrixMajor::Row => MatrixMajor::Column,
+            MatrixMajor::Column => MatrixMajor::Row,
+        };
+        self
+    }
+
+    /// The same matrix stored row by row
+    pub fn to_row_major(&self) -> Self
+    where
+        T: Copy,
+    {
+        match self.major {
+            MatrixMajor::Row => self.clone(),
+            MatrixMajor::Column => self.relayout(),
+        }
+    }
+
+    /// The same matrix stored column by column
+    pub fn to_column_major(&self) -> Self
+    where
+        T: Copy,
+    {
+        match self.major {
+            MatrixMajor::Row => self.relayout(),
+            MatrixMajor::Column => self.clone(),
         }
     }
+
+    /// Copies the data into the other layout
+    fn relayout(&self) -> Self
+    where
+        T: Copy,
+    {
+        let (non_major_dim, major_dim) = self.dim();
+        let mut array = Vec::with_capacity(self.array.len());
+        for j in 0..major_dim {
+            for i in 0..non_major_dim {
+                array.push(self.get(i, j));
+            }
+        }
+        let major = match self.major {
+            MatrixMajor::Row => MatrixMajor::Column,
+            MatrixMajor::Column => MatrixMajor::Row,
+        };
+        Self::new(major, non_major_dim, array)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use approx::AbsDiffEq;
+
     #[test]
     fn test_gaussian_elimination() {
         let mat = FullMatrix::new(
@@ -160,13 +321,80 @@ mod tests {
         );
         let b = vec![3., 3., -6.];
         let x = mat.gaussian_elimination(&b);
-        let anwser = vec![3., 1., 2.];
+        let anwser = [3., 1., 2.];
 
         for (x0, x1) in x.iter().zip(anwser.iter()) {
             assert!(x0 == x1)
         }
     }
 
+    #[test]
+    fn test_entry_and_mul_vec() {
+        let rows = FullMatrix::new(MatrixMajor::Row, 3, vec![1., 2., 3., 4., 5., 6.]);
+        let columns = FullMatrix::new(MatrixMajor::Column, 2, vec![1., 4., 2., 5., 3., 6.]);
+        assert!(rows.row_count() == 2 && columns.row_count() == 2);
+        for i in 0..2 {
+            for j in 0..3 {
+                assert!(rows.entry(i, j) == columns.entry(i, j));
+            }
+        }
+        assert!(rows.entry(1, 0) == 4.);
+        assert!(rows.mul_vec(&[1., 0., -1.]) == vec![-2., -2.]);
+        assert!(columns.mul_vec(&[1., 0., -1.]) == vec![-2., -2.]);
+    }
+
+    #[test]
+    fn test_mul_vec_uses_the_vector() {
+        // the product used to sum the rows and ignore the vector
+        let rows = FullMatrix::new(MatrixMajor::Row, 3, vec![1., 2., 3., 4., 5., 6.]);
+        assert!(rows.mul_vec(&[1., 1., 1.]) == vec![6., 15.]);
+        assert!(rows.mul_vec(&[0., 0., 0.]) == vec![0., 0.]);
+        assert!(rows.mul_vec(&[2., -1., 0.5]) == vec![1.5, 6.]);
+    }
+
+    #[test]
+    fn test_column_major_gaussian_elimination() {
+        let columns = FullMatrix::new(
+            MatrixMajor::Column,
+            3,
+            vec![1., 2., -3., 2., 1., 1., -1., -2., 1.],
+        );
+        let x = columns.gaussian_elimination(&[3., 3., -6.]);
+        assert!(x == vec![3., 1., 2.]);
+
+        let hilbert = FullMatrix::<f64>::hilbert(6);
+        let b = hilbert.mul_vec(&[1.; 6]);
+        let x = hilbert.gaussian_elimination(&b);
+        let y = hilbert.to_column_major().gaussian_elimination(&b);
+        println!("solution of column major hilbert n = 6 is {y:?}");
+        // the two back substitutions round differently
+        for (xi, yi) in x.iter().zip(y) {
+            assert!(xi.abs_diff_eq(&yi, 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_transpose_and_layouts() {
+        let rows = FullMatrix::new(MatrixMajor::Row, 3, vec![1., 2., 3., 4., 5., 6.]);
+        let transposed = rows.clone().transpose();
+        assert!(transposed.row_count() == 3 && transposed.column_count() == 2);
+        for i in 0..2 {
+            for j in 0..3 {
+                assert!(transposed.entry(j, i) == rows.entry(i, j));
+            }
+        }
+        assert!(transposed.mul_vec(&[1., -1.]) == vec![-3., -3., -3.]);
+
+        let columns = rows.to_column_major();
+        assert!(matches!(columns.major(), MatrixMajor::Column));
+        assert!(columns.array == vec![1., 4., 2., 5., 3., 6.]);
+        let back = columns.to_row_major();
+        assert!(matches!(back.major(), MatrixMajor::Row));
+        assert!(back.array == rows.array);
+        assert!(rows.to_row_major().array == rows.array);
+        assert!(transposed.to_row_major().array == vec![1., 4., 2., 5., 3., 6.]);
+    }
+
     #[test]
     fn test_gaussian_elimination_solve_hilbert() {
         let hilbert_0 = FullMatrix::hilbert(2);
@@ -0,0 +1,285 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::float_traits::{FloatConst, Sqrt};
+
+use super::{FullMatrix, MatrixError, MatrixMajor};
+
+/// `A = LL^T` of a symmetric positive definite matrix, `L` lower triangular with a
+/// positive diagonal
+#[derive(Clone, Debug)]
+pub struct CholeskyFactorization<T> {
+    lower: FullMatrix<T>,
+}
+
+/// `A = LDL^T` of a symmetric matrix, `L` unit lower triangular and `D` diagonal, the
+/// signs of `D` give the inertia of `A`
+#[derive(Clone, Debug)]
+pub struct LdltFactorization<T> {
+    lower: FullMatrix<T>,
+    diagonal: Vec<T>,
+}
+
+impl<T> FullMatrix<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialOrd
+        + Sqrt
+        + Copy,
+{
+    /// See [`CholeskyFactorization::new`]
+    pub fn cholesky(&self) -> Result<CholeskyFactorization<T>, MatrixError> {
+        CholeskyFactorization::new(self)
+    }
+
+    /// See [`LdltFactorization::new`]
+    pub fn ldlt(&self) -> Result<LdltFactorization<T>, MatrixError> {
+        LdltFactorization::new(self)
+    }
+}
+
+impl<T> CholeskyFactorization<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialOrd
+        + Sqrt
+        + Copy,
+{
+    /// `l_jj = sqrt(a_jj - Σ_k l_jk^2)` and `l_ij = (a_ij - Σ_k l_ik l_jk) / l_jj` for
+    /// `k < j < i`, in `n^3 / 3` flops, half of the LU factorization. The entries are
+    /// computed row by row for a row-major matrix and column by column for a column-major
+    /// one. Only the lower triangle of the matrix is read.
+    ///
+    /// Returns [`MatrixError::NotPositiveDefinite`] when a pivot `l_jj^2` is not positive.
+    pub fn new(mat: &FullMatrix<T>) -> Result<Self, MatrixError> {
+        if !mat.is_square_matrix() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = mat.row_count();
+        let mut l = vec![T::ZERO; n * n];
+
+        for (i, j) in lower_triangle_order(mat.major(), n) {
+            let mut l_ij = mat.entry(i, j);
+            for k in 0..j {
+                l_ij = l_ij - l[i * n + k] * l[j * n + k];
+            }
+            if i == j {
+                // also rejects NaN
+                if l_ij.partial_cmp(&T::ZERO) != Some(Ordering::Greater) {
+                    return Err(MatrixError::NotPositiveDefinite);
+                }
+                l[j * n + j] = l_ij.sqrt();
+            } else {
+                l[i * n + j] = l_ij / l[j * n + j];
+            }
+        }
+
+        Ok(Self {
+            lower: FullMatrix::new(MatrixMajor::Row, n, l),
+        })
+    }
+
+    pub fn lower(&self) -> &FullMatrix<T> {
+        &self.lower
+    }
+
+    /// Solves `Ax = b` as `Ly = b` then `L^T x = y`
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let y = self.lower.solve_lower_triangular(b, false)?;
+        self.lower.solve_lower_triangular_transposed(&y, false)
+    }
+
+    /// `det A = Π l_ii^2`
+    pub fn determinant(&self) -> T {
+        let mut det = T::ONE;
+        for i in 0..self.lower.row_count() {
+            det = det * self.lower.entry(i, i) * self.lower.entry(i, i);
+        }
+        det
+    }
+}
+
+impl<T> LdltFactorization<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + FloatConst
+        + PartialOrd
+        + Copy,
+{
+    /// `d_j = a_jj - Σ_k l_jk^2 d_k` and `l_ij = (a_ij - Σ_k l_ik l_jk d_k) / d_j` for
+    /// `k < j < i`, in the order of [`CholeskyFactorization::new`]. No square root is taken,
+    /// so indefinite matrices are factorized as well. There is no pivoting: returns
+    /// [`MatrixError::Singular`] when a `d_j` vanishes, which happens exactly when a
+    /// leading principal minor is zero. Only the lower triangle of the matrix is read.
+    pub fn new(mat: &FullMatrix<T>) -> Result<Self, MatrixError> {
+        if !mat.is_square_matrix() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = mat.row_count();
+        let mut l = vec![T::ZERO; n * n];
+        let mut d = vec![T::ZERO; n];
+
+        for (i, j) in lower_triangle_order(mat.major(), n) {
+            let mut l_ij = mat.entry(i, j);
+            for k in 0..j {
+                l_ij = l_ij - l[i * n + k] * l[j * n + k] * d[k];
+            }
+            if i == j {
+                if l_ij == T::ZERO {
+                    return Err(MatrixError::Singular);
+                }
+                l[j * n + j] = T::ONE;
+                d[j] = l_ij;
+            } else {
+                l[i * n + j] = l_ij / d[j];
+            }
+        }
+
+        Ok(Self {
+            lower: FullMatrix::new(MatrixMajor::Row, n, l),
+            diagonal: d,
+        })
+    }
+
+    /// The unit lower triangular factor
+    pub fn lower(&self) -> &FullMatrix<T> {
+        &self.lower
+    }
+
+    pub fn diagonal(&self) -> &[T] {
+        &self.diagonal
+    }
+
+    /// Solves `Ax = b` as `Ly = b`, `Dz = y` then `L^T x = z`
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let y = self.lower.solve_lower_triangular(b, true)?;
+        let z: Vec<T> = y
+            .iter()
+            .zip(self.diagonal.iter())
+            .map(|(y, d)| *y / *d)
+            .collect();
+        self.lower.solve_lower_triangular_transposed(&z, true)
+    }
+
+    /// `det A = Π d_i`
+    pub fn determinant(&self) -> T {
+        self.diagonal.iter().fold(T::ONE, |det, d| det * *d)
+    }
+}
+
+/// `(i, j)` of the lower triangle in an order where `l_ij` comes after `l_ik`, `l_jk` and
+/// `l_jj` for `k < j`: row by row for a row-major matrix, column by column for a
+/// column-major one, so that the entries of `A` are read along its storage
+fn lower_triangle_order(major: MatrixMajor, n: usize) -> Box<dyn Iterator<Item = (usize, usize)>> {
+    match major {
+        MatrixMajor::Row => Box::new((0..n).flat_map(|i| (0..=i).map(move |j| (i, j)))),
+        MatrixMajor::Column => Box::new((0..n).flat_map(move |j| (j..n).map(move |i| (i, j)))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    /// Checks `A = L D L^T` entry by entry
+    fn check_reconstruction(a: &FullMatrix<f64>, l: &FullMatrix<f64>, d: &[f64]) {
+        let n = a.row_count();
+        for i in 0..n {
+            for j in 0..n {
+                let lld: f64 = (0..n).map(|k| l.entry(i, k) * d[k] * l.entry(j, k)).sum();
+                assert!(lld.abs_diff_eq(&a.entry(i, j), 1e-13));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let a = FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![4., 12., -16., 12., 37., -43., -16., -43., 98.],
+        );
+        let cholesky = a.cholesky().unwrap();
+        // the classic example, L = [2 0 0; 6 1 0; -8 5 3]
+        let expected = [2., 0., 0., 6., 1., 0., -8., 5., 3.];
+        for (k, e) in expected.iter().enumerate() {
+            assert!(cholesky.lower().entry(k / 3, k % 3) == *e);
+        }
+        check_reconstruction(&a, cholesky.lower(), &[1.; 3]);
+        let columns = a.to_column_major().cholesky().unwrap();
+        for k in 0..9 {
+            let (i, j) = (k / 3, k % 3);
+            assert!(columns
+                .lower()
+                .entry(i, j)
+                .abs_diff_eq(&cholesky.lower().entry(i, j), 1e-15));
+        }
+        assert!(cholesky.determinant().abs_diff_eq(&36.0, 1e-12));
+
+        let x = cholesky.solve(&a.mul_vec(&[1., -2., 0.5])).unwrap();
+        for (xi, expected) in x.iter().zip([1., -2., 0.5]) {
+            assert!(xi.abs_diff_eq(&expected, 1e-12));
+        }
+
+        // the Hilbert matrices are positive definite
+        let hilbert = FullMatrix::<f64>::hilbert(8);
+        let x = hilbert
+            .cholesky()
+            .unwrap()
+            .solve(&hilbert.mul_vec(&[1.; 8]))
+            .unwrap();
+        println!("solution of hilbert n = 8 is {x:?}");
+        assert!(x.iter().all(|xi| xi.abs_diff_eq(&1.0, 1e-5)));
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let a = FullMatrix::new(MatrixMajor::Row, 2, vec![1., 2., 2., 1.]);
+        assert!(a.cholesky().unwrap_err() == MatrixError::NotPositiveDefinite);
+        let a = FullMatrix::new(MatrixMajor::Row, 2, vec![0., 0., 0., 1.]);
+        assert!(a.cholesky().unwrap_err() == MatrixError::NotPositiveDefinite);
+    }
+
+    #[test]
+    fn test_ldlt() {
+        // symmetric indefinite, with eigenvalues of both signs
+        let a = FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![1., 2., 3., 2., -1., 0., 3., 0., 2.],
+        );
+        assert!(a.cholesky().is_err());
+        let ldlt = a.ldlt().unwrap();
+        println!("d = {:?}", ldlt.diagonal());
+        check_reconstruction(&a, ldlt.lower(), ldlt.diagonal());
+        let columns = a.to_column_major().ldlt().unwrap();
+        check_reconstruction(&a, columns.lower(), columns.diagonal());
+        assert!(ldlt.diagonal().iter().any(|d| *d < 0.0));
+        // det = 1 (-2) - 2 (4) + 3 (3)
+        assert!(ldlt.determinant().abs_diff_eq(&-1.0, 1e-13));
+
+        let x = ldlt.solve(&a.mul_vec(&[2., 1., -1.])).unwrap();
+        for (xi, expected) in x.iter().zip([2., 1., -1.]) {
+            assert!(xi.abs_diff_eq(&expected, 1e-13));
+        }
+
+        // a zero leading minor needs pivoting, which is not done
+        let a = FullMatrix::new(MatrixMajor::Row, 2, vec![0., 1., 1., 0.]);
+        assert!(a.ldlt().unwrap_err() == MatrixError::Singular);
+    }
+}
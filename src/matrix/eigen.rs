@@ -0,0 +1,381 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    complex::Complex,
+    float_traits::{fl, Abs, FloatConst, FromF64, Sqrt},
+};
+
+use super::FullMatrix;
+
+const QR_ITER_LIMIT: usize = 60;
+
+impl<T> FullMatrix<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Copy,
+{
+    /// All eigenvalues of a square matrix, in no particular order.
+    ///
+    /// The matrix is balanced, reduced to upper Hessenberg form by stabilized elementary
+    /// similarity transforms, then deflated by the Francis double shift QR iteration.
+    /// Returns `None` if an eigenvalue does not converge within the iteration limit.
+    pub fn eigenvalues(&self) -> Option<Vec<Complex<T>>> {
+        assert!(self.is_square_matrix());
+        let n = self.row_count();
+        // the reduction works on rows and columns alike, so it runs on a row-major copy
+        let mut a: Vec<Vec<T>> = (0..n)
+            .map(|i| (0..n).map(|j| self.entry(i, j)).collect())
+            .collect();
+        balance(&mut a);
+        to_hessenberg(&mut a);
+        hessenberg_qr(&mut a)
+    }
+}
+
+/// `|a|` with the sign of `b`
+fn sign<T: Abs>(a: T, b: T) -> T {
+    if b >= T::ZERO {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+/// Scales rows and columns by powers of 2 so that they have comparable norms, which
+/// reduces the rounding errors of the eigenvalues without changing them
+#[allow(clippy::needless_range_loop)]
+fn balance<T>(a: &mut [Vec<T>])
+where
+    T: Add<Output = T> + Mul<Output = T> + Div<Output = T> + FromF64 + Abs + Copy,
+{
+    let n = a.len();
+    let radix: T = fl!(2.0);
+    let sqr_radix: T = fl!(4.0);
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let (mut c, mut r) = (T::ZERO, T::ZERO);
+            for j in (0..n).filter(|j| *j != i) {
+                c = c + a[j][i].abs();
+                r = r + a[i][j].abs();
+            }
+            if c == T::ZERO || r == T::ZERO {
+                continue;
+            }
+            let s = c + r;
+            let mut f = T::ONE;
+            while c < r / radix {
+                f = f * radix;
+                c = c * sqr_radix;
+            }
+            while c > r * radix {
+                f = f / radix;
+                c = c / sqr_radix;
+            }
+            if (c + r) / f < s * fl!(0.95) {
+                done = false;
+                let g = T::ONE / f;
+                for j in 0..n {
+                    a[i][j] = a[i][j] * g;
+                    a[j][i] = a[j][i] * f;
+                }
+            }
+        }
+    }
+}
+
+/// Gaussian elimination with pivoting, applied as similarity transforms below the
+/// subdiagonal
+#[allow(clippy::needless_range_loop)]
+fn to_hessenberg<T>(a: &mut [Vec<T>])
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Abs + Copy,
+{
+    let n = a.len();
+    for m in 1..n.saturating_sub(1) {
+        let mut pivot = m;
+        for j in m..n {
+            if a[j][m - 1].abs() > a[pivot][m - 1].abs() {
+                pivot = j;
+            }
+        }
+        let x = a[pivot][m - 1];
+        if pivot != m {
+            a.swap(pivot, m);
+            for row in a.iter_mut() {
+                row.swap(pivot, m);
+            }
+        }
+        if x == T::ZERO {
+            continue;
+        }
+        for i in (m + 1)..n {
+            let y = a[i][m - 1] / x;
+            if y == T::ZERO {
+                continue;
+            }
+            a[i][m - 1] = T::ZERO;
+            for j in m..n {
+                a[i][j] = a[i][j] - y * a[m][j];
+            }
+            for row in a.iter_mut() {
+                row[m] = row[m] + y * row[i];
+            }
+        }
+    }
+}
+
+/// Eigenvalues of the upper Hessenberg matrix `a`, which is overwritten
+#[allow(clippy::needless_range_loop)]
+fn hessenberg_qr<T>(a: &mut [Vec<T>]) -> Option<Vec<Complex<T>>>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + FromF64
+        + Abs
+        + Sqrt
+        + Copy,
+{
+    let n = a.len();
+    let mut norm = T::ZERO;
+    for (i, row) in a.iter().enumerate() {
+        for x in row.iter().skip(i.saturating_sub(1)) {
+            norm = norm + x.abs();
+        }
+    }
+
+    let mut eigenvalues = vec![Complex::from_real(T::ZERO); n];
+    // accumulated exceptional shifts
+    let mut shift = T::ZERO;
+    // the active block is a[..end][..end]
+    let mut end = n;
+    while end > 0 {
+        let nn = end - 1;
+        let mut iter_count = 0;
+        loop {
+            // look for a negligible subdiagonal element to split the block at
+            let mut l = nn;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == T::ZERO {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() <= T::EPSILON * s {
+                    a[l][l - 1] = T::ZERO;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                // one root found
+                eigenvalues[nn] = Complex::from_real(x + shift);
+                end -= 1;
+                break;
+            }
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+            if l + 1 == nn {
+                // two roots found, of the trailing 2 x 2 block
+                let p = (y - x) * fl!(0.5);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x = x + shift;
+                if q >= T::ZERO {
+                    let z = p + sign(z, p);
+                    eigenvalues[nn - 1] = Complex::from_real(x + z);
+                    eigenvalues[nn] = if z != T::ZERO {
+                        Complex::from_real(x - w / z)
+                    } else {
+                        Complex::from_real(x + z)
+                    };
+                } else {
+                    eigenvalues[nn] = Complex::new(x + p, -z);
+                    eigenvalues[nn - 1] = Complex::new(x + p, z);
+                }
+                end -= 2;
+                break;
+            }
+
+            if iter_count == QR_ITER_LIMIT {
+                return None;
+            }
+            if iter_count == 10 || iter_count == 20 {
+                // exceptional shift
+                shift = shift + x;
+                for (i, row) in a.iter_mut().enumerate().take(nn + 1) {
+                    row[i] = row[i] - x;
+                }
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = s * fl!(0.75);
+                y = x;
+                w = -s * s * fl!(0.4375);
+            }
+            iter_count += 1;
+
+            // two consecutive small subdiagonal elements
+            let mut m = nn - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[m][m];
+                let (r0, s0) = (x - z, y - z);
+                p = (r0 * s0 - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r0 - s0;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u <= T::EPSILON * v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m..(nn - 1) {
+                a[i + 2][i] = T::ZERO;
+                if i != m {
+                    a[i + 2][i - 1] = T::ZERO;
+                }
+            }
+
+            // double QR step on rows l..=nn and columns m..=nn
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k + 1 != nn {
+                        a[k + 2][k - 1]
+                    } else {
+                        T::ZERO
+                    };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != T::ZERO {
+                        p = p / x;
+                        q = q / x;
+                        r = r / x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s == T::ZERO {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p = p + s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q = q / p;
+                r = r / p;
+                for j in k..=nn {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+                    if k + 1 != nn {
+                        p = p + r * a[k + 2][j];
+                        a[k + 2][j] = a[k + 2][j] - p * z;
+                    }
+                    a[k + 1][j] = a[k + 1][j] - p * y;
+                    a[k][j] = a[k][j] - p * x;
+                }
+                for row in a.iter_mut().take(nn.min(k + 3) + 1).skip(l) {
+                    let mut p = x * row[k] + y * row[k + 1];
+                    if k + 1 != nn {
+                        p = p + z * row[k + 2];
+                        row[k + 2] = row[k + 2] - p * r;
+                    }
+                    row[k + 1] = row[k + 1] - p * q;
+                    row[k] = row[k] - p;
+                }
+            }
+        }
+    }
+
+    Some(eigenvalues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::matrix::MatrixMajor;
+
+    fn sorted(mut eigenvalues: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+        eigenvalues.sort_by(|z0, z1| (z0.re, z0.im).partial_cmp(&(z1.re, z1.im)).unwrap());
+        eigenvalues
+    }
+
+    #[test]
+    fn test_real_eigenvalues() {
+        // symmetric, with eigenvalues 2 - √2, 2, 2 + √2
+        let mat = FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0],
+        );
+        let eigenvalues = sorted(mat.eigenvalues().unwrap());
+        let sqrt2 = std::f64::consts::SQRT_2;
+        for (z, expected) in eigenvalues.iter().zip([2.0 - sqrt2, 2.0, 2.0 + sqrt2]) {
+            assert!(z.re.abs_diff_eq(&expected, 1e-14) && z.im == 0.0);
+        }
+
+        let eigenvalues = FullMatrix::<f64>::hilbert(6).eigenvalues().unwrap();
+        println!("eigenvalues of hilbert n = 6: {eigenvalues:?}");
+        let trace: f64 = (0..6).map(|i| 1.0 / (2 * i + 1) as f64).sum();
+        let sum: f64 = eigenvalues.iter().map(|z| z.re).sum();
+        assert!(sum.abs_diff_eq(&trace, 1e-14));
+    }
+
+    #[test]
+    fn test_complex_eigenvalues() {
+        // companion matrix of x^4 - 1
+        let mat = FullMatrix::new(
+            MatrixMajor::Row,
+            4,
+            vec![
+                0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+        );
+        let eigenvalues = sorted(mat.eigenvalues().unwrap());
+        let expected = [(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)];
+        for (z, (re, im)) in eigenvalues.iter().zip(expected) {
+            assert!(
+                (*z - Complex::new(re, im)).norm().abs_diff_eq(&0.0, 1e-14),
+                "{z}"
+            );
+        }
+
+        // the transpose, read column by column, has the same eigenvalues
+        let transposed = sorted(mat.clone().transpose().eigenvalues().unwrap());
+        let columns = sorted(mat.to_column_major().eigenvalues().unwrap());
+        for ((z, t), c) in eigenvalues.iter().zip(transposed).zip(columns) {
+            assert!((*z - t).norm().abs_diff_eq(&0.0, 1e-14) && *z == c);
+        }
+    }
+}
@@ -0,0 +1,547 @@
+mod condition;
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::float_traits::{Abs, FloatConst};
+
+use super::{FullMatrix, MatrixError, MatrixMajor};
+
+/// Pivot choice of the Gaussian elimination at each column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pivoting {
+    /// The diagonal entry, fails on a zero pivot even for a regular matrix
+    None,
+    /// The largest entry in absolute value of the column, swapping rows
+    Partial,
+    /// The largest entry of the remaining submatrix, swapping rows and columns
+    Complete,
+    /// An entry that is the largest of both its row and its column in the remaining
+    /// submatrix, found by alternate row and column searches
+    Rook,
+}
+
+/// `PAQ = LU`, `L` unit lower triangular and `U` upper triangular, where the permutation
+/// `Q` of the columns is the identity unless the pivoting is complete or rook.
+/// The factors are kept, so that each further solve costs `O(n^2)`.
+#[derive(Clone, Debug)]
+pub struct LuFactorization<T> {
+    /// `L` below the diagonal without its unit diagonal, and `U` on and above it, in
+    /// row-major order
+    lu: Vec<T>,
+    n: usize,
+    pivoting: Pivoting,
+    /// row `i` of `PAQ` is row `permutation[i]` of `A`
+    permutation: Vec<usize>,
+    /// column `j` of `PAQ` is column `column_permutation[j]` of `A`
+    column_permutation: Vec<usize>,
+    /// `true` for an odd number of row and column swaps
+    odd_permutation: bool,
+    growth_factor: T,
+    /// `||A||_1`, kept for the condition estimate
+    norm_1: T,
+}
+
+/// Solution of a linear system with figures of its accuracy
+#[derive(Clone, Debug)]
+pub struct LinearSolveResult<T> {
+    pub(super) solution: Vec<T>,
+    pub(super) pivoting: Pivoting,
+    pub(super) growth_factor: T,
+    pub(super) condition_estimate: T,
+    pub(super) forward_error_bound: T,
+}
+
+impl<T: Copy> LinearSolveResult<T> {
+    pub fn solution(&self) -> &[T] {
+        &self.solution
+    }
+
+    pub fn into_solution(self) -> Vec<T> {
+        self.solution
+    }
+
+    pub fn pivoting(&self) -> Pivoting {
+        self.pivoting
+    }
+
+    /// See [`LuFactorization::growth_factor`]
+    pub fn growth_factor(&self) -> T {
+        self.growth_factor
+    }
+
+    /// See [`LuFactorization::condition_estimate`]
+    pub fn condition_estimate(&self) -> T {
+        self.condition_estimate
+    }
+
+    /// Estimated bound on the relative error `||x - x̂||_1 / ||x̂||_1` of the solution
+    pub fn forward_error_bound(&self) -> T {
+        self.forward_error_bound
+    }
+}
+
+impl<T> FullMatrix<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + Abs
+        + Copy,
+{
+    /// See [`LuFactorization::new`]
+    pub fn lu(&self) -> Result<LuFactorization<T>, MatrixError> {
+        LuFactorization::new(self)
+    }
+
+    /// See [`LuFactorization::with_pivoting`]
+    pub fn lu_with_pivoting(&self, pivoting: Pivoting) -> Result<LuFactorization<T>, MatrixError> {
+        LuFactorization::with_pivoting(self, pivoting)
+    }
+}
+
+impl<T> LuFactorization<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + Abs
+        + Copy,
+{
+    /// Gaussian elimination with partial pivoting, see [`LuFactorization::with_pivoting`]
+    pub fn new(mat: &FullMatrix<T>) -> Result<Self, MatrixError> {
+        Self::with_pivoting(mat, Pivoting::Partial)
+    }
+
+    /// Gaussian elimination with the given pivoting. Returns [`MatrixError::Singular`]
+    /// when the chosen pivot is zero.
+    pub fn with_pivoting(mat: &FullMatrix<T>, pivoting: Pivoting) -> Result<Self, MatrixError> {
+        if !mat.is_square_matrix() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = mat.row_count();
+        let norm_1 = mat.norm_1();
+        let mut lu = mat.to_row_major().array;
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut column_permutation: Vec<usize> = (0..n).collect();
+        let mut odd_permutation = false;
+
+        let max_abs = |lu: &[T]| {
+            lu.iter()
+                .fold(T::ZERO, |m, x| if x.abs() > m { x.abs() } else { m })
+        };
+        let initial_max = max_abs(&lu);
+        let mut max = initial_max;
+
+        for col in 0..n {
+            let (row, pivot_col) = pivot_position(&lu, n, col, pivoting);
+            if lu[row * n + pivot_col] == T::ZERO {
+                return Err(MatrixError::Singular);
+            }
+            if row != col {
+                for k in 0..n {
+                    lu.swap(row * n + k, col * n + k);
+                }
+                permutation.swap(row, col);
+                odd_permutation = !odd_permutation;
+            }
+            if pivot_col != col {
+                for k in 0..n {
+                    lu.swap(k * n + pivot_col, k * n + col);
+                }
+                column_permutation.swap(pivot_col, col);
+                odd_permutation = !odd_permutation;
+            }
+
+            let p = lu[col * n + col];
+            for row in (col + 1)..n {
+                let l = lu[row * n + col] / p;
+                lu[row * n + col] = l;
+                for k in (col + 1)..n {
+                    let updated = lu[row * n + k] - l * lu[col * n + k];
+                    if updated.abs() > max {
+                        max = updated.abs();
+                    }
+                    lu[row * n + k] = updated;
+                }
+            }
+        }
+
+        Ok(Self {
+            lu,
+            n,
+            pivoting,
+            permutation,
+            column_permutation,
+            odd_permutation,
+            growth_factor: max / initial_max,
+            norm_1,
+        })
+    }
+
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    pub fn pivoting(&self) -> Pivoting {
+        self.pivoting
+    }
+
+    /// Row `i` of `PAQ` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Column `j` of `PAQ` is column `column_permutation()[j]` of `A`
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.column_permutation
+    }
+
+    /// `max |a_ij^(k)| / max |a_ij|` over the entries of all the intermediate matrices of
+    /// the elimination, which bounds the growth of the rounding errors. It can reach
+    /// `2^(n-1)` with partial pivoting, while rook and complete pivoting keep it small.
+    pub fn growth_factor(&self) -> T {
+        self.growth_factor
+    }
+
+    /// The unit lower triangular factor
+    pub fn lower(&self) -> FullMatrix<T> {
+        let n = self.n;
+        let v = (0..n * n)
+            .map(|k| match (k / n, k % n) {
+                (i, j) if i == j => T::ONE,
+                (i, j) if i > j => self.lu[k],
+                _ => T::ZERO,
+            })
+            .collect();
+        FullMatrix::new(MatrixMajor::Row, n, v)
+    }
+
+    /// The upper triangular factor
+    pub fn upper(&self) -> FullMatrix<T> {
+        let n = self.n;
+        let v = (0..n * n)
+            .map(|k| if k / n <= k % n { self.lu[k] } else { T::ZERO })
+            .collect();
+        FullMatrix::new(MatrixMajor::Row, n, v)
+    }
+
+    /// Solves `Ax = b` by forward substitution with `L` and back substitution with `U`,
+    /// then undoes the column permutation
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let n = self.n;
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut x: Vec<T> = self.permutation.iter().map(|i| b[*i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.lu[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] = x[i] - self.lu[i * n + j] * x[j];
+            }
+            x[i] = x[i] / self.lu[i * n + i];
+        }
+
+        let mut solution = vec![T::ZERO; n];
+        for (j, xj) in x.into_iter().enumerate() {
+            solution[self.column_permutation[j]] = xj;
+        }
+        Ok(solution)
+    }
+
+    /// Solves `A^T x = b`, as `U^T L^T P x = Q^T b`, with the same factors
+    pub fn solve_transposed(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let n = self.n;
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut y: Vec<T> = self.column_permutation.iter().map(|j| b[*j]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                y[i] = y[i] - self.lu[j * n + i] * y[j];
+            }
+            y[i] = y[i] / self.lu[i * n + i];
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                y[i] = y[i] - self.lu[j * n + i] * y[j];
+            }
+        }
+
+        let mut solution = vec![T::ZERO; n];
+        for (i, yi) in y.into_iter().enumerate() {
+            solution[self.permutation[i]] = yi;
+        }
+        Ok(solution)
+    }
+
+    /// Solves `AX = B` column by column, `X` is returned in row-major order
+    pub fn solve_many(&self, b: &FullMatrix<T>) -> Result<FullMatrix<T>, MatrixError> {
+        if b.row_count() != self.n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let m = b.column_count();
+        let mut x = vec![T::ZERO; self.n * m];
+        for j in 0..m {
+            let column: Vec<T> = (0..self.n).map(|i| b.entry(i, j)).collect();
+            for (i, xi) in self.solve(&column)?.into_iter().enumerate() {
+                x[i * m + j] = xi;
+            }
+        }
+        Ok(FullMatrix::new(MatrixMajor::Row, m, x))
+    }
+
+    /// Product of the pivots, with the sign of the permutation
+    pub fn determinant(&self) -> T {
+        let mut det = T::ONE;
+        for i in 0..self.n {
+            det = det * self.lu[i * self.n + i];
+        }
+        if self.odd_permutation {
+            -det
+        } else {
+            det
+        }
+    }
+
+    pub fn inverse(&self) -> FullMatrix<T> {
+        self.solve_many(&FullMatrix::identity(self.n))
+            .expect("identity has the dimension of the factorization")
+    }
+}
+
+/// `(row, column)` of the pivot for column `col` of the row-major `n × n` matrix `lu`
+fn pivot_position<T>(lu: &[T], n: usize, col: usize, pivoting: Pivoting) -> (usize, usize)
+where
+    T: Abs + Copy,
+{
+    let at = |i: usize, j: usize| lu[i * n + j].abs();
+    let column_max = |j: usize| (col..n).fold(col, |r, i| if at(i, j) > at(r, j) { i } else { r });
+    let row_max = |i: usize| (col..n).fold(col, |c, j| if at(i, j) > at(i, c) { j } else { c });
+
+    match pivoting {
+        Pivoting::None => (col, col),
+        Pivoting::Partial => (column_max(col), col),
+        Pivoting::Complete => {
+            let mut pivot = (col, col);
+            for i in col..n {
+                let j = row_max(i);
+                if at(i, j) > at(pivot.0, pivot.1) {
+                    pivot = (i, j);
+                }
+            }
+            pivot
+        }
+        Pivoting::Rook => {
+            let (mut r, mut c) = (column_max(col), col);
+            loop {
+                let next_c = row_max(r);
+                if at(r, next_c) <= at(r, c) {
+                    break;
+                }
+                c = next_c;
+                let next_r = column_max(c);
+                if at(next_r, c) <= at(r, c) {
+                    break;
+                }
+                r = next_r;
+            }
+            (r, c)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    fn sample() -> FullMatrix<f64> {
+        // a zero leading entry, which needs a row swap
+        FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![0., 2., 1., 1., 1., -1., 4., -2., 3.],
+        )
+    }
+
+    #[test]
+    fn test_factors() {
+        let a = sample();
+        let lu = a.lu().unwrap();
+        let (l, u) = (lu.lower(), lu.upper());
+        println!("permutation = {:?}", lu.permutation());
+        for i in 0..3 {
+            for j in 0..3 {
+                let mut lu_ij = 0.0;
+                for k in 0..3 {
+                    lu_ij += l.entry(i, k) * u.entry(k, j);
+                }
+                let pa_ij = a.entry(lu.permutation()[i], j);
+                assert!(lu_ij.abs_diff_eq(&pa_ij, 1e-14));
+                // |l_ij| <= 1 thanks to the pivoting
+                assert!(l.entry(i, j).abs() <= 1.0);
+            }
+        }
+        // det = 0 (3 + 2) - 2 (3 + 4) + 1 (-2 - 4)
+        assert!(lu.determinant().abs_diff_eq(&-20.0, 1e-13));
+    }
+
+    #[test]
+    fn test_solve() {
+        let lu = sample().lu().unwrap();
+        let x = lu.solve(&[5., 2., 3.]).unwrap();
+        for (xi, expected) in x.iter().zip([1., 2., 1.]) {
+            assert!(xi.abs_diff_eq(&expected, 1e-14));
+        }
+        assert!(lu.solve(&[1., 2.]) == Err(MatrixError::DimensionMismatch));
+
+        // column-major input gives the same factorization
+        let columns = FullMatrix::new(
+            MatrixMajor::Column,
+            3,
+            vec![0., 1., 4., 2., 1., -2., 1., -1., 3.],
+        );
+        let y = columns.lu().unwrap().solve(&[5., 2., 3.]).unwrap();
+        assert!(x == y);
+    }
+
+    #[test]
+    fn test_solve_many_and_inverse() {
+        let a = sample();
+        let lu = a.lu().unwrap();
+        let b = FullMatrix::new(MatrixMajor::Row, 2, vec![5., 1., -2., 0., 9., 0.]);
+        let x = lu.solve_many(&b).unwrap();
+        assert!(x.row_count() == 3 && x.column_count() == 2);
+        for j in 0..2 {
+            let column: Vec<f64> = (0..3).map(|i| x.entry(i, j)).collect();
+            for (ax, bi) in a.mul_vec(&column).iter().zip((0..3).map(|i| b.entry(i, j))) {
+                assert!(ax.abs_diff_eq(&bi, 1e-14));
+            }
+        }
+
+        let inverse = lu.inverse();
+        for j in 0..3 {
+            let column: Vec<f64> = (0..3).map(|i| inverse.entry(i, j)).collect();
+            for (i, e) in a.mul_vec(&column).iter().enumerate() {
+                assert!(e.abs_diff_eq(&if i == j { 1.0 } else { 0.0 }, 1e-14));
+            }
+        }
+    }
+
+    #[test]
+    fn test_singular() {
+        let a = FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![1., 2., 3., 2., 4., 6., 1., 0., 1.],
+        );
+        assert!(a.lu().unwrap_err() == MatrixError::Singular);
+        let a = FullMatrix::new(MatrixMajor::Row, 3, vec![1., 2., 3., 4., 5., 6.]);
+        assert!(a.lu().unwrap_err() == MatrixError::NotSquare);
+    }
+
+    #[test]
+    fn test_hilbert() {
+        for n in [5, 10] {
+            let hilbert = FullMatrix::<f64>::hilbert(n);
+            let x = hilbert
+                .lu()
+                .unwrap()
+                .solve(&hilbert.mul_vec(&vec![1.; n]))
+                .unwrap();
+            println!("solution of hilbert n = {n} is {x:?}");
+        }
+    }
+
+    /// Unit diagonal, -1 below and 1 in the last column, where partial pivoting grows
+    /// the last column to 2^(n-1)
+    fn wilkinson(n: usize) -> FullMatrix<f64> {
+        let v = (0..n * n)
+            .map(|k| match (k / n, k % n) {
+                (_, j) if j == n - 1 => 1.0,
+                (i, j) if i == j => 1.0,
+                (i, j) if i > j => -1.0,
+                _ => 0.0,
+            })
+            .collect();
+        FullMatrix::new(MatrixMajor::Row, n, v)
+    }
+
+    #[test]
+    fn test_pivoting_strategies() {
+        let a = sample();
+        let b = [5., 2., 3.];
+        assert!(a.lu_with_pivoting(Pivoting::None).unwrap_err() == MatrixError::Singular);
+        for pivoting in [Pivoting::Partial, Pivoting::Complete, Pivoting::Rook] {
+            let lu = a.lu_with_pivoting(pivoting).unwrap();
+            assert!(lu.determinant().abs_diff_eq(&-20.0, 1e-13));
+            let result = a.solve_with_pivoting(&b, pivoting).unwrap();
+            assert!(result.pivoting() == pivoting);
+            for (xi, expected) in result.solution().iter().zip([1., 2., 1.]) {
+                assert!(xi.abs_diff_eq(&expected, 1e-14), "{pivoting:?}");
+            }
+        }
+
+        // the largest entry is off the first column
+        let a = FullMatrix::new(MatrixMajor::Row, 2, vec![1., 5., 2., 1.]);
+        let lu = a.lu_with_pivoting(Pivoting::Complete).unwrap();
+        assert!(lu.permutation() == [0, 1] && lu.column_permutation() == [1, 0]);
+        assert!(lu.determinant().abs_diff_eq(&-9.0, 1e-14));
+        let x = lu.solve(&[11., 4.]).unwrap();
+        assert!(x[0].abs_diff_eq(&1.0, 1e-14) && x[1].abs_diff_eq(&2.0, 1e-14));
+    }
+
+    #[test]
+    fn test_growth_factor() {
+        let n = 20;
+        let a = wilkinson(n);
+        let b = a.mul_vec(&vec![1.; n]);
+        for pivoting in [Pivoting::Partial, Pivoting::Complete, Pivoting::Rook] {
+            let result = a.solve_with_pivoting(&b, pivoting).unwrap();
+            println!("{pivoting:?}: growth factor = {}", result.growth_factor());
+            if pivoting == Pivoting::Partial {
+                assert!(result.growth_factor() == 2_f64.powi(n as i32 - 1));
+            } else {
+                assert!(result.growth_factor() <= 2.0);
+            }
+            assert!(result.solution().iter().all(|x| x.abs_diff_eq(&1.0, 1e-12)));
+        }
+
+        for n in [6, 10] {
+            let hilbert = FullMatrix::<f64>::hilbert(n);
+            let b = hilbert.mul_vec(&vec![1.; n]);
+            for pivoting in [
+                Pivoting::None,
+                Pivoting::Partial,
+                Pivoting::Complete,
+                Pivoting::Rook,
+            ] {
+                let result = hilbert.solve_with_pivoting(&b, pivoting).unwrap();
+                let error = result
+                    .solution()
+                    .iter()
+                    .fold(0.0_f64, |m, x| m.max((x - 1.0).abs()));
+                println!(
+                    "hilbert n = {n}, {pivoting:?}: growth factor = {}, max error = {error:e}",
+                    result.growth_factor()
+                );
+                assert!(result.growth_factor() <= 1.0);
+            }
+        }
+    }
+}
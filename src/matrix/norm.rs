@@ -0,0 +1,73 @@
+use std::ops::{Add, Mul};
+
+use crate::float_traits::{Abs, FloatConst, Sqrt};
+
+use super::{FullMatrix, MatrixMajor};
+
+impl<T> FullMatrix<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + PartialOrd + FloatConst + Abs + Copy,
+{
+    /// `max_j Σ_i |a_ij|`, the largest absolute column sum
+    pub fn norm_1(&self) -> T {
+        max(&self.absolute_sums().1)
+    }
+
+    /// `max_i Σ_j |a_ij|`, the largest absolute row sum
+    pub fn norm_inf(&self) -> T {
+        max(&self.absolute_sums().0)
+    }
+
+    /// `(Σ_j |a_ij|, Σ_i |a_ij|)`, the absolute row and column sums, in one pass over the
+    /// storage
+    fn absolute_sums(&self) -> (Vec<T>, Vec<T>) {
+        let (non_major_dim, major_dim) = self.dim();
+        // sums along the contiguous lines, and across them
+        let mut along = vec![T::ZERO; non_major_dim];
+        let mut across = vec![T::ZERO; major_dim];
+        for (k, a) in self.array.iter().enumerate() {
+            let (line, position) = (k / major_dim, k % major_dim);
+            along[line] = along[line] + a.abs();
+            across[position] = across[position] + a.abs();
+        }
+        match self.major {
+            MatrixMajor::Row => (along, across),
+            MatrixMajor::Column => (across, along),
+        }
+    }
+
+    /// `sqrt(Σ a_ij^2)`
+    pub fn norm_frobenius(&self) -> T
+    where
+        T: Sqrt,
+    {
+        // the sum of squares does not depend on the layout
+        self.array
+            .iter()
+            .fold(T::ZERO, |sum, a| sum + *a * *a)
+            .sqrt()
+    }
+}
+
+fn max<T: PartialOrd + FloatConst + Copy>(v: &[T]) -> T {
+    v.iter().fold(T::ZERO, |m, s| if *s > m { *s } else { m })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    #[test]
+    fn test_norms() {
+        let rows = FullMatrix::new(MatrixMajor::Row, 3, vec![1., -2., 3., -4., 5., -6.]);
+        let columns = FullMatrix::new(MatrixMajor::Column, 2, vec![1., -4., -2., 5., 3., -6.]);
+        for mat in [rows, columns] {
+            assert!(mat.norm_1() == 9.0);
+            assert!(mat.norm_inf() == 15.0);
+            assert!(mat.norm_frobenius().abs_diff_eq(&91_f64.sqrt(), 1e-15));
+        }
+        assert!(FullMatrix::<f64>::identity(4).norm_frobenius() == 2.0);
+    }
+}
@@ -0,0 +1,128 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::float_traits::{Abs, FloatConst, Sqrt};
+
+use super::FullMatrix;
+
+const JACOBI_SWEEP_LIMIT: usize = 60;
+
+impl<T> FullMatrix<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + PartialOrd
+        + FloatConst
+        + Abs
+        + Sqrt
+        + Copy,
+{
+    /// The `min(m, n)` singular values, in decreasing order.
+    ///
+    /// One-sided Jacobi: plane rotations orthogonalize the columns (the rows of a wide
+    /// matrix) pair by pair, sweeping until every pair is orthogonal to working
+    /// precision, then the singular values are the column norms. Small singular values
+    /// are found to high relative accuracy.
+    pub fn singular_values(&self) -> Vec<T> {
+        let (m, n) = (self.row_count(), self.column_count());
+        let mut v: Vec<Vec<T>> = if m >= n {
+            (0..n)
+                .map(|j| (0..m).map(|i| self.entry(i, j)).collect())
+                .collect()
+        } else {
+            (0..m)
+                .map(|i| (0..n).map(|j| self.entry(i, j)).collect())
+                .collect()
+        };
+        let dot = |x: &[T], y: &[T]| x.iter().zip(y).fold(T::ZERO, |s, (a, b)| s + *a * *b);
+
+        for _ in 0..JACOBI_SWEEP_LIMIT {
+            let mut rotated = false;
+            for p in 0..v.len() {
+                for q in (p + 1)..v.len() {
+                    let (alpha, beta, gamma) =
+                        (dot(&v[p], &v[p]), dot(&v[q], &v[q]), dot(&v[p], &v[q]));
+                    if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    // the rotation that zeroes the off-diagonal entry of the 2 × 2 Gram matrix
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let sign = if zeta >= T::ZERO { T::ONE } else { -T::ONE };
+                    let t = sign / (zeta.abs() + (T::ONE + zeta * zeta).sqrt());
+                    let c = T::ONE / (T::ONE + t * t).sqrt();
+                    let s = c * t;
+                    for k in 0..v[p].len() {
+                        let (x, y) = (v[p][k], v[q][k]);
+                        v[p][k] = c * x - s * y;
+                        v[q][k] = s * x + c * y;
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut sigma: Vec<T> = v.iter().map(|x| dot(x, x).sqrt()).collect();
+        sigma.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        sigma
+    }
+
+    /// `σ_max / σ_min`, the exact condition number in the 2-norm, from the singular
+    /// values. It is infinite for a singular matrix.
+    pub fn cond_2(&self) -> T {
+        let sigma = self.singular_values();
+        sigma[0] / sigma[sigma.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    use crate::matrix::MatrixMajor;
+
+    #[test]
+    fn test_singular_values() {
+        // [3 2 2; 2 3 -2] has singular values 5 and 3
+        let wide = FullMatrix::new(MatrixMajor::Row, 3, vec![3., 2., 2., 2., 3., -2.]);
+        let tall = FullMatrix::new(MatrixMajor::Column, 2, vec![3., 2., 2., 3., 2., -2.]);
+        for mat in [wide, tall] {
+            let sigma = mat.singular_values();
+            println!("singular values = {sigma:?}");
+            assert!(sigma.len() == 2);
+            assert!(sigma[0].abs_diff_eq(&5.0, 1e-14) && sigma[1].abs_diff_eq(&3.0, 1e-14));
+        }
+
+        // the Frobenius norm is the 2-norm of the singular values
+        let a = FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![0., 2., 1., 1., 1., -1., 4., -2., 3.],
+        );
+        let sigma = a.singular_values();
+        let sum: f64 = sigma.iter().map(|s| s * s).sum();
+        assert!(sum.sqrt().abs_diff_eq(&a.norm_frobenius(), 1e-13));
+        // and |det| is their product
+        assert!(sigma.iter().product::<f64>().abs_diff_eq(&20.0, 1e-12));
+    }
+
+    #[test]
+    fn test_cond_2() {
+        assert!(FullMatrix::<f64>::identity(3).cond_2() == 1.0);
+        let a = FullMatrix::new(MatrixMajor::Row, 2, vec![1., 0., 0., 1e-3]);
+        assert!(a.cond_2().abs_diff_eq(&1e3, 1e-10));
+
+        // the condition numbers of the Hilbert matrices grow like e^(3.5 n)
+        for (n, expected) in [(4, 1.5514e4), (8, 1.5258e10)] {
+            let cond = FullMatrix::<f64>::hilbert(n).cond_2();
+            println!("cond_2 of hilbert n = {n} is {cond:e}");
+            assert!(((cond - expected) / expected).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::ops::{Div, Mul, Sub};
+
+use crate::float_traits::FloatConst;
+
+use super::{FullMatrix, MatrixError};
+
+impl<T> FullMatrix<T>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + FloatConst + PartialEq + Copy,
+{
+    fn check_triangular_system(&self, b: &[T]) -> Result<(), MatrixError> {
+        if !self.is_square_matrix() {
+            return Err(MatrixError::NotSquare);
+        }
+        if b.len() != self.row_count() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(())
+    }
+
+    /// Solves `Lx = b` by forward substitution, where `L` is the lower triangle of the
+    /// matrix, with ones on the diagonal if `unit_diagonal`. The upper triangle is not read.
+    pub fn solve_lower_triangular(
+        &self,
+        b: &[T],
+        unit_diagonal: bool,
+    ) -> Result<Vec<T>, MatrixError> {
+        self.check_triangular_system(b)?;
+        let mut x = b.to_vec();
+        for i in 0..x.len() {
+            for j in 0..i {
+                x[i] = x[i] - self.entry(i, j) * x[j];
+            }
+            if !unit_diagonal {
+                x[i] = divide_by_pivot(x[i], self.entry(i, i))?;
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves `Ux = b` by back substitution, where `U` is the upper triangle of the
+    /// matrix, with ones on the diagonal if `unit_diagonal`. The lower triangle is not read.
+    pub fn solve_upper_triangular(
+        &self,
+        b: &[T],
+        unit_diagonal: bool,
+    ) -> Result<Vec<T>, MatrixError> {
+        self.check_triangular_system(b)?;
+        let mut x = b.to_vec();
+        for i in (0..x.len()).rev() {
+            for j in (i + 1)..x.len() {
+                x[i] = x[i] - self.entry(i, j) * x[j];
+            }
+            if !unit_diagonal {
+                x[i] = divide_by_pivot(x[i], self.entry(i, i))?;
+            }
+        }
+        Ok(x)
+    }
+
+    /// Solves `L^T x = b` by back substitution, where `L` is the lower triangle of the
+    /// matrix, without forming the transpose
+    pub fn solve_lower_triangular_transposed(
+        &self,
+        b: &[T],
+        unit_diagonal: bool,
+    ) -> Result<Vec<T>, MatrixError> {
+        self.check_triangular_system(b)?;
+        let mut x = b.to_vec();
+        for i in (0..x.len()).rev() {
+            for j in (i + 1)..x.len() {
+                x[i] = x[i] - self.entry(j, i) * x[j];
+            }
+            if !unit_diagonal {
+                x[i] = divide_by_pivot(x[i], self.entry(i, i))?;
+            }
+        }
+        Ok(x)
+    }
+}
+
+fn divide_by_pivot<T>(x: T, pivot: T) -> Result<T, MatrixError>
+where
+    T: Div<Output = T> + FloatConst + PartialEq,
+{
+    if pivot == T::ZERO {
+        Err(MatrixError::Singular)
+    } else {
+        Ok(x / pivot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::matrix::MatrixMajor;
+
+    #[test]
+    fn test_triangular_solves() {
+        // the upper triangle holds garbage that must be ignored by the lower solves
+        let mat = FullMatrix::new(
+            MatrixMajor::Row,
+            3,
+            vec![2., 9., 9., 1., 4., 9., -1., 2., 1.],
+        );
+        // [2 0 0; 1 4 0; -1 2 1] x = [2, 9, 4] for x = [1, 2, 1]
+        assert!(mat.solve_lower_triangular(&[2., 9., 4.], false).unwrap() == vec![1., 2., 1.]);
+        // [1 0 0; 1 1 0; -1 2 1] x = [1, 3, 6]
+        assert!(mat.solve_lower_triangular(&[1., 3., 6.], true).unwrap() == vec![1., 2., 3.]);
+        // [2 1 -1; 0 4 2; 0 0 1] x = [3, 10, 1]
+        assert!(
+            mat.solve_lower_triangular_transposed(&[3., 10., 1.], false)
+                .unwrap()
+                == vec![1., 2., 1.]
+        );
+
+        let upper = FullMatrix::new(MatrixMajor::Row, 2, vec![2., 1., 7., 0.]);
+        assert!(
+            upper.solve_upper_triangular(&[1., 1.], false).unwrap_err() == MatrixError::Singular
+        );
+        assert!(upper.solve_upper_triangular(&[3., 1.], true).unwrap() == vec![2., 1.]);
+        assert!(
+            upper.solve_upper_triangular(&[1.], true).unwrap_err()
+                == MatrixError::DimensionMismatch
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::float_traits::FloatConst;
+
+/// Square tridiagonal matrix stored by its three diagonals
+#[derive(Clone, Debug)]
+pub struct TridiagonalMatrix<T> {
+    /// `A[i + 1][i]`, `n - 1` entries
+    lower: Vec<T>,
+    /// `A[i][i]`, `n` entries
+    diag: Vec<T>,
+    /// `A[i][i + 1]`, `n - 1` entries
+    upper: Vec<T>,
+}
+
+impl<T> TridiagonalMatrix<T> {
+    pub fn new(lower: Vec<T>, diag: Vec<T>, upper: Vec<T>) -> Self {
+        assert!(!diag.is_empty());
+        assert!(lower.len() + 1 == diag.len());
+        assert!(upper.len() + 1 == diag.len());
+        Self { lower, diag, upper }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.diag.len()
+    }
+}
+
+impl<T> TridiagonalMatrix<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + FloatConst
+        + Copy,
+{
+    /// Thomas algorithm, `O(n)`. No pivoting is done, which is safe for diagonally
+    /// dominant matrices.
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        thomas(&self.lower, &self.diag, &self.upper, b)
+    }
+
+    /// Solves the cyclic system whose matrix is `self` plus the corner entries
+    /// `A[0][n - 1] = top_right` and `A[n - 1][0] = bottom_left`, by the Sherman–Morrison
+    /// formula on top of two tridiagonal solves. Needs `n >= 3`.
+    pub fn solve_cyclic(&self, top_right: T, bottom_left: T, b: &[T]) -> Vec<T> {
+        let n = self.dim();
+        assert!(n >= 3);
+        assert!(b.len() == n);
+
+        let gamma = -self.diag[0];
+        let mut diag = self.diag.clone();
+        diag[0] = diag[0] - gamma;
+        diag[n - 1] = diag[n - 1] - bottom_left * top_right / gamma;
+
+        let mut x = thomas(&self.lower, &diag, &self.upper, b);
+
+        let mut u = vec![T::ZERO; n];
+        u[0] = gamma;
+        u[n - 1] = bottom_left;
+        let z = thomas(&self.lower, &diag, &self.upper, &u);
+
+        let fact =
+            (x[0] + top_right * x[n - 1] / gamma) / (T::ONE + z[0] + top_right * z[n - 1] / gamma);
+        for (xi, zi) in x.iter_mut().zip(z) {
+            *xi = *xi - fact * zi;
+        }
+        x
+    }
+}
+
+fn thomas<T>(lower: &[T], diag: &[T], upper: &[T], b: &[T]) -> Vec<T>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + FloatConst + Copy,
+{
+    let n = diag.len();
+    assert!(b.len() == n);
+
+    let mut upper_mod = vec![T::ZERO; n];
+    let mut x = vec![T::ZERO; n];
+
+    let mut pivot = diag[0];
+    x[0] = b[0] / pivot;
+    for i in 1..n {
+        upper_mod[i - 1] = upper[i - 1] / pivot;
+        pivot = diag[i] - lower[i - 1] * upper_mod[i - 1];
+        x[i] = (b[i] - lower[i - 1] * x[i - 1]) / pivot;
+    }
+    for i in (0..(n - 1)).rev() {
+        x[i] = x[i] - upper_mod[i] * x[i + 1];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::AbsDiffEq;
+
+    #[test]
+    fn test_solve() {
+        let mat = TridiagonalMatrix::new(
+            vec![1.0, 1.0, 2.0],
+            vec![4.0, 4.0, 4.0, 5.0],
+            vec![1.0, 2.0, 1.0],
+        );
+        let x = [1.0, -2.0, 3.0, 0.5];
+        let b = [2.0, -1.0, 10.5, 8.5];
+        for (xi, expected) in mat.solve(&b).iter().zip(x) {
+            assert!(xi.abs_diff_eq(&expected, 1e-14));
+        }
+
+        let mat = TridiagonalMatrix::new(vec![], vec![2.0], vec![]);
+        assert!(mat.solve(&[3.0]) == vec![1.5]);
+    }
+
+    #[test]
+    fn test_solve_cyclic() {
+        // [[4, 1, 0, 2], [1, 4, 1, 0], [0, 1, 4, 1], [3, 0, 1, 4]]
+        let mat = TridiagonalMatrix::new(vec![1.0, 1.0, 1.0], vec![4.0; 4], vec![1.0, 1.0, 1.0]);
+        let x = [1.0, -2.0, 3.0, 0.5];
+        let b = [3.0, -4.0, 10.5, 8.0];
+        for (xi, expected) in mat.solve_cyclic(2.0, 3.0, &b).iter().zip(x) {
+            assert!(xi.abs_diff_eq(&expected, 1e-14));
+        }
+    }
+}